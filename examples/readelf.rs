@@ -9,18 +9,15 @@ use elf_rs::*;
 fn read_elf(filename: &str) -> Result<(), ()> {
     let mut elf_file = File::open(filename).map_err(|e| {
         println!("failed to open file {}: {}", filename, e);
-        ()
     })?;
     let mut elf_buf = Vec::<u8>::new();
 
     elf_file.read_to_end(&mut elf_buf).map_err(|e| {
         println!("failed to read file {}: {}", filename, e);
-        ()
     })?;
 
-    let elf = Elf::from_bytes(&mut elf_buf).map_err(|e| {
+    let elf = Elf::from_bytes(&elf_buf).map_err(|e| {
         println!("failed to extract elf file {}: {:?}", filename, e);
-        ()
     })?;
 
     println!("{:#x?}", elf);
@@ -35,6 +32,37 @@ fn read_elf(filename: &str) -> Result<(), ()> {
         println!("{:#x?}", s);
     }
 
+    for d in elf.dynamic_iter().into_iter().flatten() {
+        println!("{:#x?}", d);
+    }
+
+    for s in elf.section_header_iter() {
+        if let Some(relocs) = s.relocation_iter() {
+            for r in relocs {
+                let sym_name = r
+                    .symbol()
+                    .and_then(|sym| sym.symbol_name())
+                    .and_then(|n| core::str::from_utf8(n).ok())
+                    .unwrap_or("");
+                println!(
+                    "{:016x} {:<24} {} + {:x}",
+                    r.offset(),
+                    r.relocation_type(),
+                    sym_name,
+                    r.addend().unwrap_or(0)
+                );
+            }
+        }
+    }
+
+    for sym in elf.symbol_iter().into_iter().flatten() {
+        println!("{:#x?}", sym);
+    }
+
+    for sym in elf.dynamic_symbol_iter().into_iter().flatten() {
+        println!("{:#x?}", sym);
+    }
+
     if let Some(s) = elf.lookup_section(b".text") {
         println!(".test section: {:#x?}", s);
     }
@@ -50,7 +78,7 @@ fn main() -> Result<(), ()> {
     }
 
     let filename = &args[1];
-    read_elf(&filename)?;
+    read_elf(filename)?;
 
     Ok(())
 }
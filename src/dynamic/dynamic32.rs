@@ -0,0 +1,25 @@
+use crate::dynamic::{DynamicRaw, DynamicTag};
+use crate::endian::{Endianness, Pod, I32, U32};
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct Dynamic32<E: Endianness> {
+    d_tag: I32<E>,
+    d_val: U32<E>,
+}
+
+unsafe impl<E: Endianness> Pod for Dynamic32<E> {}
+
+impl<E: Endianness> DynamicRaw for Dynamic32<E> {
+    fn tag(&self) -> DynamicTag {
+        self.raw_tag().into()
+    }
+
+    fn raw_tag(&self) -> i64 {
+        self.d_tag.get() as i64
+    }
+
+    fn value(&self) -> u64 {
+        self.d_val.get() as u64
+    }
+}
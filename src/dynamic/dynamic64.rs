@@ -0,0 +1,25 @@
+use crate::dynamic::{DynamicRaw, DynamicTag};
+use crate::endian::{Endianness, Pod, I64, U64};
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct Dynamic64<E: Endianness> {
+    d_tag: I64<E>,
+    d_val: U64<E>,
+}
+
+unsafe impl<E: Endianness> Pod for Dynamic64<E> {}
+
+impl<E: Endianness> DynamicRaw for Dynamic64<E> {
+    fn tag(&self) -> DynamicTag {
+        self.raw_tag().into()
+    }
+
+    fn raw_tag(&self) -> i64 {
+        self.d_tag.get()
+    }
+
+    fn value(&self) -> u64 {
+        self.d_val.get()
+    }
+}
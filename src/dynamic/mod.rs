@@ -0,0 +1,220 @@
+mod dynamic32;
+mod dynamic64;
+
+pub use dynamic32::Dynamic32;
+pub use dynamic64::Dynamic64;
+
+const DT_LOOS: i64 = 0x6000000d;
+const DT_HIOS: i64 = 0x6ffff000;
+const DT_LOPROC: i64 = 0x70000000;
+const DT_HIPROC: i64 = 0x7fffffff;
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DynamicTag {
+    DT_NULL,            // 0
+    DT_NEEDED,          // 1
+    DT_PLTRELSZ,        // 2
+    DT_PLTGOT,          // 3
+    DT_HASH,            // 4
+    DT_STRTAB,          // 5
+    DT_SYMTAB,          // 6
+    DT_RELA,            // 7
+    DT_RELASZ,          // 8
+    DT_RELAENT,         // 9
+    DT_STRSZ,           // 10
+    DT_SYMENT,          // 11
+    DT_INIT,            // 12
+    DT_FINI,            // 13
+    DT_SONAME,          // 14
+    DT_RPATH,           // 15
+    DT_SYMBOLIC,        // 16
+    DT_REL,             // 17
+    DT_RELSZ,           // 18
+    DT_RELENT,          // 19
+    DT_PLTREL,          // 20
+    DT_DEBUG,           // 21
+    DT_TEXTREL,         // 22
+    DT_JMPREL,          // 23
+    DT_BIND_NOW,        // 24
+    DT_INIT_ARRAY,      // 25
+    DT_FINI_ARRAY,      // 26
+    DT_INIT_ARRAYSZ,    // 27
+    DT_FINI_ARRAYSZ,    // 28
+    DT_RUNPATH,         // 29
+    DT_FLAGS,           // 30
+    DT_PREINIT_ARRAY,   // 32
+    DT_PREINIT_ARRAYSZ, // 33
+    DT_SYMTAB_SHNDX,    // 34
+    DT_RELRSZ,          // 35
+    DT_RELR,            // 36
+    DT_RELRENT,         // 37
+    DT_GNU_PRELINKED,   // 0x6ffffdf5
+    DT_GNU_CONFLICTSZ,  // 0x6ffffdf6
+    DT_GNU_LIBLISTSZ,   // 0x6ffffdf7
+    DT_CHECKSUM,        // 0x6ffffdf8
+    DT_PLTPADSZ,        // 0x6ffffdf9
+    DT_MOVEENT,         // 0x6ffffdfa
+    DT_MOVESZ,          // 0x6ffffdfb
+    DT_FEATURE_1,       // 0x6ffffdfc
+    DT_POSFLAG_1,       // 0x6ffffdfd
+    DT_SYMINSZ,         // 0x6ffffdfe
+    DT_SYMINENT,        // 0x6ffffdff
+    DT_GNU_HASH,        // 0x6ffffef5
+    DT_TLSDESC_PLT,     // 0x6ffffef6
+    DT_TLSDESC_GOT,     // 0x6ffffef7
+    DT_GNU_CONFLICT,    // 0x6ffffef8
+    DT_GNU_LIBLIST,     // 0x6ffffef9
+    DT_CONFIG,          // 0x6ffffefa
+    DT_DEPAUDIT,        // 0x6ffffefb
+    DT_AUDIT,           // 0x6ffffefc
+    DT_PLTPAD,          // 0x6ffffefd
+    DT_MOVETAB,         // 0x6ffffefe
+    DT_SYMINFO,         // 0x6ffffeff
+    DT_VERSYM,          // 0x6ffffff0
+    DT_RELACOUNT,       // 0x6ffffff9
+    DT_RELCOUNT,        // 0x6ffffffa
+    DT_FLAGS_1,         // 0x6ffffffb
+    DT_VERDEF,          // 0x6ffffffc
+    DT_VERDEFNUM,       // 0x6ffffffd
+    DT_VERNEED,         // 0x6ffffffe
+    DT_VERNEEDNUM,      // 0x6fffffff
+    DT_AUXILIARY,       // 0x7ffffffd
+    DT_FILTER,          // 0x7fffffff
+    OsSpecific(i64),
+    ProcessorSpecific(i64),
+    Unknown(i64),
+}
+
+impl From<i64> for DynamicTag {
+    fn from(n: i64) -> Self {
+        match n {
+            0 => DynamicTag::DT_NULL,
+            1 => DynamicTag::DT_NEEDED,
+            2 => DynamicTag::DT_PLTRELSZ,
+            3 => DynamicTag::DT_PLTGOT,
+            4 => DynamicTag::DT_HASH,
+            5 => DynamicTag::DT_STRTAB,
+            6 => DynamicTag::DT_SYMTAB,
+            7 => DynamicTag::DT_RELA,
+            8 => DynamicTag::DT_RELASZ,
+            9 => DynamicTag::DT_RELAENT,
+            10 => DynamicTag::DT_STRSZ,
+            11 => DynamicTag::DT_SYMENT,
+            12 => DynamicTag::DT_INIT,
+            13 => DynamicTag::DT_FINI,
+            14 => DynamicTag::DT_SONAME,
+            15 => DynamicTag::DT_RPATH,
+            16 => DynamicTag::DT_SYMBOLIC,
+            17 => DynamicTag::DT_REL,
+            18 => DynamicTag::DT_RELSZ,
+            19 => DynamicTag::DT_RELENT,
+            20 => DynamicTag::DT_PLTREL,
+            21 => DynamicTag::DT_DEBUG,
+            22 => DynamicTag::DT_TEXTREL,
+            23 => DynamicTag::DT_JMPREL,
+            24 => DynamicTag::DT_BIND_NOW,
+            25 => DynamicTag::DT_INIT_ARRAY,
+            26 => DynamicTag::DT_FINI_ARRAY,
+            27 => DynamicTag::DT_INIT_ARRAYSZ,
+            28 => DynamicTag::DT_FINI_ARRAYSZ,
+            29 => DynamicTag::DT_RUNPATH,
+            30 => DynamicTag::DT_FLAGS,
+            32 => DynamicTag::DT_PREINIT_ARRAY,
+            33 => DynamicTag::DT_PREINIT_ARRAYSZ,
+            34 => DynamicTag::DT_SYMTAB_SHNDX,
+            35 => DynamicTag::DT_RELRSZ,
+            36 => DynamicTag::DT_RELR,
+            37 => DynamicTag::DT_RELRENT,
+            0x6ffffdf5 => DynamicTag::DT_GNU_PRELINKED,
+            0x6ffffdf6 => DynamicTag::DT_GNU_CONFLICTSZ,
+            0x6ffffdf7 => DynamicTag::DT_GNU_LIBLISTSZ,
+            0x6ffffdf8 => DynamicTag::DT_CHECKSUM,
+            0x6ffffdf9 => DynamicTag::DT_PLTPADSZ,
+            0x6ffffdfa => DynamicTag::DT_MOVEENT,
+            0x6ffffdfb => DynamicTag::DT_MOVESZ,
+            0x6ffffdfc => DynamicTag::DT_FEATURE_1,
+            0x6ffffdfd => DynamicTag::DT_POSFLAG_1,
+            0x6ffffdfe => DynamicTag::DT_SYMINSZ,
+            0x6ffffdff => DynamicTag::DT_SYMINENT,
+            0x6ffffef5 => DynamicTag::DT_GNU_HASH,
+            0x6ffffef6 => DynamicTag::DT_TLSDESC_PLT,
+            0x6ffffef7 => DynamicTag::DT_TLSDESC_GOT,
+            0x6ffffef8 => DynamicTag::DT_GNU_CONFLICT,
+            0x6ffffef9 => DynamicTag::DT_GNU_LIBLIST,
+            0x6ffffefa => DynamicTag::DT_CONFIG,
+            0x6ffffefb => DynamicTag::DT_DEPAUDIT,
+            0x6ffffefc => DynamicTag::DT_AUDIT,
+            0x6ffffefd => DynamicTag::DT_PLTPAD,
+            0x6ffffefe => DynamicTag::DT_MOVETAB,
+            0x6ffffeff => DynamicTag::DT_SYMINFO,
+            0x6ffffff0 => DynamicTag::DT_VERSYM,
+            0x6ffffff9 => DynamicTag::DT_RELACOUNT,
+            0x6ffffffa => DynamicTag::DT_RELCOUNT,
+            0x6ffffffb => DynamicTag::DT_FLAGS_1,
+            0x6ffffffc => DynamicTag::DT_VERDEF,
+            0x6ffffffd => DynamicTag::DT_VERDEFNUM,
+            0x6ffffffe => DynamicTag::DT_VERNEED,
+            0x6fffffff => DynamicTag::DT_VERNEEDNUM,
+            0x7ffffffd => DynamicTag::DT_AUXILIARY,
+            0x7fffffff => DynamicTag::DT_FILTER,
+            x @ DT_LOOS..=DT_HIOS => DynamicTag::OsSpecific(x),
+            x @ DT_LOPROC..=DT_HIPROC => DynamicTag::ProcessorSpecific(x),
+            n => DynamicTag::Unknown(n),
+        }
+    }
+}
+
+bitflags! {
+    /// Flags of a `DT_FLAGS` entry.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct DynamicFlags: u64 {
+        const DF_ORIGIN     = 0x1;
+        const DF_SYMBOLIC   = 0x2;
+        const DF_TEXTREL    = 0x4;
+        const DF_BIND_NOW   = 0x8;
+        const DF_STATIC_TLS = 0x10;
+    }
+}
+
+bitflags! {
+    /// Flags of a `DT_FLAGS_1` entry.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct DynamicFlags1: u64 {
+        const DF_1_NOW        = 0x1;
+        const DF_1_GLOBAL     = 0x2;
+        const DF_1_GROUP      = 0x4;
+        const DF_1_NODELETE   = 0x8;
+        const DF_1_LOADFLTR   = 0x10;
+        const DF_1_INITFIRST  = 0x20;
+        const DF_1_NOOPEN     = 0x40;
+        const DF_1_ORIGIN     = 0x80;
+        const DF_1_DIRECT     = 0x100;
+        const DF_1_TRANS      = 0x200;
+        const DF_1_INTERPOSE  = 0x400;
+        const DF_1_NODEFLIB   = 0x800;
+        const DF_1_NODUMP     = 0x1000;
+        const DF_1_CONFALT    = 0x2000;
+        const DF_1_ENDFILTEE  = 0x4000;
+        const DF_1_DISPRELDNE = 0x8000;
+        const DF_1_DISPRELPND = 0x10000;
+        const DF_1_NODIRECT   = 0x20000;
+        const DF_1_IGNMULDEF  = 0x40000;
+        const DF_1_NOKSYMS    = 0x80000;
+        const DF_1_NOHDR      = 0x100000;
+        const DF_1_EDITED     = 0x200000;
+        const DF_1_NORELOC    = 0x400000;
+        const DF_1_SYMINTPOSE = 0x800000;
+        const DF_1_GLOBAUDIT  = 0x1000000;
+        const DF_1_SINGLETON  = 0x2000000;
+        const DF_1_STUB       = 0x4000000;
+        const DF_1_PIE        = 0x8000000;
+    }
+}
+
+pub trait DynamicRaw {
+    fn tag(&self) -> DynamicTag;
+
+    fn raw_tag(&self) -> i64;
+
+    /// The `d_val` or `d_ptr` of the entry, depending on its tag.
+    fn value(&self) -> u64;
+}
@@ -0,0 +1,129 @@
+use super::ElfFile;
+use crate::note::BuildId;
+use crate::Elf;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::string::ToString;
+use std::vec;
+use std::vec::Vec;
+
+/// The directory `gdb` and `elfutils` look for debug files in by default.
+const DEFAULT_DEBUG_DIR: &str = "/usr/lib/debug";
+
+/// A file name as recorded in the ELF file.
+#[cfg(unix)]
+fn file_name(bytes: &[u8]) -> Option<&OsStr> {
+    use std::os::unix::ffi::OsStrExt;
+    Some(OsStr::from_bytes(bytes))
+}
+
+#[cfg(not(unix))]
+fn file_name(bytes: &[u8]) -> Option<&OsStr> {
+    core::str::from_utf8(bytes).ok().map(OsStr::new)
+}
+
+/// A debug file found by a `DebugFileResolver`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugFile {
+    pub path: PathBuf,
+    pub data: Vec<u8>,
+}
+
+/// Finds the separate debug files of ELF files in the conventional local
+/// locations, checking that each candidate really belongs to the file.
+#[derive(Debug, Clone)]
+pub struct DebugFileResolver {
+    debug_dirs: Vec<PathBuf>,
+}
+
+impl Default for DebugFileResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DebugFileResolver {
+    /// A resolver searching `/usr/lib/debug`.
+    pub fn new() -> Self {
+        Self {
+            debug_dirs: vec![PathBuf::from(DEFAULT_DEBUG_DIR)],
+        }
+    }
+
+    /// Replaces the global debug directories, which are searched for
+    /// `.build-id/xx/yyyy.debug` paths and mirror the directory tree of the
+    /// files for `.gnu_debuglink` names.
+    pub fn with_debug_dirs<I>(mut self, debug_dirs: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<PathBuf>,
+    {
+        self.debug_dirs = debug_dirs.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn debug_dirs(&self) -> &[PathBuf] {
+        &self.debug_dirs
+    }
+
+    /// Finds the debug file of `elf_file`, which was read from `path`.
+    ///
+    /// Looks up the build-id under each debug directory, then the
+    /// `.gnu_debuglink` name next to `path`, in its `.debug` directory and
+    /// under each debug directory. Build-id matches must have the same
+    /// build-id and debuglink matches the recorded CRC.
+    pub fn find_debug_file(&self, elf_file: &dyn ElfFile, path: &Path) -> Option<DebugFile> {
+        if let Some(build_id) = elf_file.build_id() {
+            if let Some(file) = self.find_by_build_id(build_id) {
+                return Some(file);
+            }
+        }
+
+        let link = elf_file.debug_link()?;
+        let name = file_name(link.file_name)?;
+        // Canonicalize the file itself, so that a bare file name has the
+        // current directory as parent.
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let dir = path.parent()?;
+        let mut candidates = vec![dir.join(name), dir.join(".debug").join(name)];
+        if let Ok(relative) = dir.strip_prefix("/") {
+            candidates.extend(self.debug_dirs.iter().map(|d| d.join(relative).join(name)));
+        }
+        candidates
+            .into_iter()
+            .filter(|candidate| *candidate != path)
+            .find_map(|candidate| {
+                let data = std::fs::read(&candidate).ok()?;
+                (crc32fast::hash(&data) == link.crc).then_some(DebugFile {
+                    path: candidate,
+                    data,
+                })
+            })
+    }
+
+    /// Finds the supplementary file named by the `.gnu_debugaltlink` of
+    /// `elf_file`, a debug file read from `path`, and checks its build-id.
+    pub fn find_alt_file(&self, elf_file: &dyn ElfFile, path: &Path) -> Option<DebugFile> {
+        let link = elf_file.debug_alt_link()?;
+        // An absolute name replaces the directory it is joined to.
+        let candidate = path.parent()?.join(file_name(link.file_name)?);
+        Self::read_with_build_id(candidate, link.build_id)
+            .or_else(|| self.find_by_build_id(link.build_id))
+    }
+
+    fn find_by_build_id(&self, build_id: &[u8]) -> Option<DebugFile> {
+        let debug_path = BuildId::new(build_id).debug_path()?.to_string();
+        self.debug_dirs
+            .iter()
+            .find_map(|dir| Self::read_with_build_id(dir.join(&debug_path), build_id))
+    }
+
+    fn read_with_build_id(path: PathBuf, build_id: &[u8]) -> Option<DebugFile> {
+        let data = std::fs::read(&path).ok()?;
+        let elf = Elf::from_bytes(&data).ok()?;
+        if elf.build_id() != Some(build_id) {
+            return None;
+        }
+        Some(DebugFile { path, data })
+    }
+}
@@ -0,0 +1,48 @@
+use crate::elf_header::ElfEndian;
+use crate::endian::read_u32;
+
+/// The separate debug file named by a `.gnu_debuglink` section.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DebugLink<'a> {
+    pub file_name: &'a [u8],
+    /// CRC-32 of the whole debug file.
+    pub crc: u32,
+}
+
+impl<'a> DebugLink<'a> {
+    /// Parses the NUL terminated file name, padded to four bytes, and the
+    /// CRC that follows it.
+    pub(crate) fn parse(endian: ElfEndian, data: &'a [u8]) -> Option<Self> {
+        let len = data.iter().position(|&x| x == b'\0')?;
+        Some(Self {
+            file_name: &data[..len],
+            crc: read_u32(endian, data, (len + 4) & !3)?,
+        })
+    }
+}
+
+/// The supplementary file named by a `.gnu_debugaltlink` section, into
+/// which `dwz` moved debug info shared between files.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DebugAltLink<'a> {
+    /// Absolute, or relative to the directory of the debug file.
+    pub file_name: &'a [u8],
+    /// Build-id of the supplementary file.
+    pub build_id: &'a [u8],
+}
+
+impl<'a> DebugAltLink<'a> {
+    /// Parses the NUL terminated file name and the build-id that fills the
+    /// rest of the section.
+    pub(crate) fn parse(data: &'a [u8]) -> Option<Self> {
+        let len = data.iter().position(|&x| x == b'\0')?;
+        let build_id = &data[len + 1..];
+        if build_id.is_empty() {
+            return None;
+        }
+        Some(Self {
+            file_name: &data[..len],
+            build_id,
+        })
+    }
+}
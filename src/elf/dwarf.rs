@@ -0,0 +1,122 @@
+use super::{ElfFile, SectionHeaderEntry, SectionNameIndex};
+use crate::elf_header::ElfEndian;
+use crate::section_header::SectionType;
+use crate::Error;
+use alloc::borrow::Cow;
+use alloc::format;
+use core::fmt;
+use gimli::{RunTimeEndian, SectionId};
+
+/// The `gimli` reader over sections of a `DwarfData`.
+pub type DwarfReader<'a> = gimli::EndianSlice<'a, RunTimeEndian>;
+
+pub(crate) fn gimli_endian(endian: ElfEndian) -> RunTimeEndian {
+    match endian {
+        ElfEndian::BigEndian => RunTimeEndian::Big,
+        _ => RunTimeEndian::Little,
+    }
+}
+
+/// The contents of `section`, decompressed if needed.
+fn section_data(section: SectionHeaderEntry<'_>) -> Result<Cow<'_, [u8]>, Error> {
+    if section.sh_type() == SectionType::SHT_NOBITS {
+        return Ok(Cow::Borrowed(&[]));
+    }
+    match section.try_compression_header()? {
+        None => section.try_content().map(Cow::Borrowed),
+        #[cfg(any(feature = "zlib", feature = "zstd"))]
+        Some(_) => section.decompressed_content().map(Cow::Owned),
+        #[cfg(not(any(feature = "zlib", feature = "zstd")))]
+        Some(header) => Err(Error::UnsupportedCompression(header.ch_type.into())),
+    }
+}
+
+/// Loads the DWARF sections of `elf_file`, or its `.dwo` sections if `dwo`
+/// is set. Missing sections are empty, and `.zdebug_*` sections stand in for
+/// missing `.debug_*` ones.
+pub(crate) fn load_sections<'a, T>(
+    elf_file: &'a dyn ElfFile,
+    dwo: bool,
+    mut map: impl FnMut(Cow<'a, [u8]>) -> T,
+) -> Result<gimli::DwarfSections<T>, Error> {
+    let index = SectionNameIndex::new(elf_file);
+    gimli::DwarfSections::load(|id: SectionId| {
+        let name = if dwo { id.dwo_name() } else { Some(id.name()) };
+        let section = name.and_then(|name| {
+            index.lookup(name.as_bytes()).or_else(|| {
+                let zdebug = format!(".z{}", name.strip_prefix('.')?);
+                index.lookup(zdebug.as_bytes())
+            })
+        });
+        match section {
+            Some(section) => section_data(section).map(&mut map),
+            None => Ok(map(Cow::Borrowed(&[]))),
+        }
+    })
+}
+
+/// The DWARF sections of an ELF file, ready to be read with `gimli`.
+///
+/// Compressed sections are decompressed when the `zlib` or `zstd` feature is
+/// enabled. Relocations are not applied, so the debug info of relocatable
+/// objects may be incomplete.
+pub struct DwarfData<'a> {
+    endian: RunTimeEndian,
+    sections: gimli::DwarfSections<Cow<'a, [u8]>>,
+}
+
+impl<'a> DwarfData<'a> {
+    /// Loads the `.debug_*` sections of `elf_file`.
+    pub fn load(elf_file: &'a dyn ElfFile) -> Result<Self, Error> {
+        Self::load_impl(elf_file, false)
+    }
+
+    /// Loads the `.debug_*.dwo` sections of a split DWARF object.
+    pub fn load_dwo(elf_file: &'a dyn ElfFile) -> Result<Self, Error> {
+        Self::load_impl(elf_file, true)
+    }
+
+    fn load_impl(elf_file: &'a dyn ElfFile, dwo: bool) -> Result<Self, Error> {
+        Ok(Self {
+            endian: gimli_endian(elf_file.elf_header().endianness()),
+            sections: load_sections(elf_file, dwo, |data| data)?,
+        })
+    }
+
+    pub fn endian(&self) -> RunTimeEndian {
+        self.endian
+    }
+
+    /// The `gimli::Dwarf` over the loaded sections.
+    pub fn dwarf(&self) -> gimli::Dwarf<DwarfReader<'_>> {
+        self.sections
+            .borrow(|data| gimli::EndianSlice::new(data, self.endian))
+    }
+
+    /// Like `dwarf`, with `sup` as the supplementary object file that
+    /// `DW_FORM_*_sup` attributes refer to.
+    pub fn dwarf_with_sup<'b>(&'b self, sup: &'b DwarfData<'_>) -> gimli::Dwarf<DwarfReader<'b>> {
+        let mut dwarf = self.dwarf();
+        dwarf.set_sup(sup.dwarf());
+        dwarf
+    }
+
+    /// The `gimli::Dwarf` of a split DWARF object, sharing the address and
+    /// range tables of the skeleton units in `parent`.
+    pub fn dwo_dwarf<'b>(
+        &'b self,
+        parent: &gimli::Dwarf<DwarfReader<'b>>,
+    ) -> gimli::Dwarf<DwarfReader<'b>> {
+        let mut dwarf = self.dwarf();
+        dwarf.make_dwo(parent);
+        dwarf
+    }
+}
+
+impl<'a> fmt::Debug for DwarfData<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DWARF Data")
+            .field("endian", &self.endian)
+            .finish()
+    }
+}
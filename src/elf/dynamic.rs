@@ -0,0 +1,176 @@
+use super::{raw_nth, ElfFile, StringTable};
+use crate::dynamic::{Dynamic32, Dynamic64, DynamicFlags, DynamicFlags1, DynamicRaw, DynamicTag};
+use crate::elf_header::{ElfClass, ElfEndian};
+use crate::endian::{BigEndian, LittleEndian};
+use core::fmt;
+use core::ops;
+
+pub struct DynamicEntry<'a> {
+    strtab: Option<StringTable<'a>>,
+    inner: &'a dyn DynamicRaw,
+}
+
+impl<'a> ops::Deref for DynamicEntry<'a> {
+    type Target = dyn DynamicRaw + 'a;
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<'a> DynamicEntry<'a> {
+    pub fn new(strtab: Option<StringTable<'a>>, inner: &'a dyn DynamicRaw) -> Self {
+        Self { strtab, inner }
+    }
+
+    /// String value of entries such as `DT_NEEDED`, `DT_SONAME`, `DT_RPATH`
+    /// and `DT_RUNPATH`, looked up in the `DT_STRTAB` string table.
+    pub fn string_value(&self) -> Option<&'a [u8]> {
+        match self.inner.tag() {
+            DynamicTag::DT_NEEDED
+            | DynamicTag::DT_SONAME
+            | DynamicTag::DT_RPATH
+            | DynamicTag::DT_RUNPATH
+            | DynamicTag::DT_AUXILIARY
+            | DynamicTag::DT_FILTER
+            | DynamicTag::DT_CONFIG
+            | DynamicTag::DT_DEPAUDIT
+            | DynamicTag::DT_AUDIT => {}
+            _ => return None,
+        }
+        self.strtab?.get(self.inner.value() as usize).ok()
+    }
+
+    pub fn flags(&self) -> Option<DynamicFlags> {
+        match self.inner.tag() {
+            DynamicTag::DT_FLAGS => Some(DynamicFlags::from_bits_retain(self.inner.value())),
+            _ => None,
+        }
+    }
+
+    pub fn flags_1(&self) -> Option<DynamicFlags1> {
+        match self.inner.tag() {
+            DynamicTag::DT_FLAGS_1 => Some(DynamicFlags1::from_bits_retain(self.inner.value())),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> fmt::Debug for DynamicEntry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("Dynamic");
+        s.field("tag", &self.tag());
+        match self.string_value() {
+            Some(v) => s.field("value", &core::str::from_utf8(v).unwrap_or("")),
+            None => s.field("value", &self.value()),
+        };
+        s.finish()
+    }
+}
+
+/// Iterates the entries of a dynamic section up to its terminating
+/// `DT_NULL` entry.
+pub struct DynamicIter<'a> {
+    elf_file: &'a dyn ElfFile,
+    content: &'a [u8],
+    strtab: Option<StringTable<'a>>,
+    index: usize,
+}
+
+impl<'a> DynamicIter<'a> {
+    /// Creates an iterator over `content`. Strings are resolved through
+    /// `DT_STRTAB`, falling back to `strtab` if that cannot be mapped to the
+    /// file.
+    pub fn new(
+        elf_file: &'a dyn ElfFile,
+        content: &'a [u8],
+        strtab: Option<StringTable<'a>>,
+    ) -> Self {
+        let mut iter = Self {
+            elf_file,
+            content,
+            strtab: None,
+            index: 0,
+        };
+        iter.strtab = iter.dt_strtab().or(strtab);
+        iter
+    }
+
+    /// The string table the entries are resolved through.
+    pub fn string_table(&self) -> Option<StringTable<'a>> {
+        self.strtab
+    }
+
+    fn dt_strtab(&self) -> Option<StringTable<'a>> {
+        let mut addr = None;
+        let mut size = None;
+        for i in 0.. {
+            let Some(entry) = self.dynamic_raw_nth(i) else {
+                break;
+            };
+            match entry.tag() {
+                DynamicTag::DT_NULL => break,
+                DynamicTag::DT_STRTAB => addr = Some(entry.value()),
+                DynamicTag::DT_STRSZ => size = Some(entry.value()),
+                _ => {}
+            }
+        }
+        self.elf_file
+            .read_at_vaddr(addr?, size? as usize)
+            .map(StringTable::new)
+    }
+
+    fn dynamic_raw_nth(&self, index: usize) -> Option<&'a dyn DynamicRaw> {
+        let content = self.content;
+        let elf_header = self.elf_file.elf_header();
+        match (elf_header.class(), elf_header.endianness()) {
+            (ElfClass::Elf64, ElfEndian::BigEndian) => {
+                raw_nth::<Dynamic64<BigEndian>>(content, index).map(|d| d as _)
+            }
+            (ElfClass::Elf64, _) => {
+                raw_nth::<Dynamic64<LittleEndian>>(content, index).map(|d| d as _)
+            }
+            (_, ElfEndian::BigEndian) => {
+                raw_nth::<Dynamic32<BigEndian>>(content, index).map(|d| d as _)
+            }
+            _ => raw_nth::<Dynamic32<LittleEndian>>(content, index).map(|d| d as _),
+        }
+    }
+}
+
+impl<'a> Iterator for DynamicIter<'a> {
+    type Item = DynamicEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.dynamic_raw_nth(self.index)
+            .filter(|d| d.tag() != DynamicTag::DT_NULL)
+            .map(|d| DynamicEntry::new(self.strtab, d))
+            .map(|e| {
+                self.index += 1;
+                e
+            })
+    }
+}
+
+/// Iterates the string values of all dynamic entries with a given tag.
+pub struct DynamicStringIter<'a> {
+    inner: Option<DynamicIter<'a>>,
+    tag: DynamicTag,
+}
+
+impl<'a> DynamicStringIter<'a> {
+    pub fn new(inner: Option<DynamicIter<'a>>, tag: DynamicTag) -> Self {
+        Self { inner, tag }
+    }
+}
+
+impl<'a> Iterator for DynamicStringIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tag = self.tag;
+        self.inner
+            .as_mut()?
+            .filter(|d| d.tag() == tag)
+            .find_map(|d| d.string_value())
+    }
+}
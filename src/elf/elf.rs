@@ -1,23 +1,25 @@
 use super::{
-    ElfFile, ElfHeader, ElfType, ProgramHeaderEntry, ProgramHeaderIter, SectionHeaderEntry,
-    SectionHeaderIter,
+    raw_nth, raw_slice, validate, ElfFile, ElfHeader, ElfType, Layout, ProgramHeaderEntry,
+    ProgramHeaderIter, SectionHeaderEntry, SectionHeaderIter, ValidationPolicy, ValidationReport,
 };
-use crate::elf_header::{ElfClass, ElfHeader32, ElfHeader64, ELF_MAGIC};
-use crate::program_header::{ProgramHeader32, ProgramHeader64};
-use crate::section_header::{SectionHeader32, SectionHeader64};
+use crate::elf_header::{
+    ElfClass, ElfEndian, ElfHeader32, ElfHeader64, ElfHeaderRaw, EI_DATA, ELF_MAGIC,
+};
+use crate::endian::{BigEndian, Endianness, LittleEndian, Pod};
+use crate::program_header::{ProgramHeader32, ProgramHeader64, PN_XNUM};
+use crate::section_header::{SectionHeader32, SectionHeader64, SectionHeaderRaw};
 use crate::Error;
 use core::fmt;
 use core::marker::PhantomData;
 use core::mem::size_of;
-use core::slice::from_raw_parts;
 
 #[derive(Debug)]
 pub enum ElfType64 {}
 
 impl ElfType for ElfType64 {
-    type ElfHeader = ElfHeader64;
-    type ProgramHeader = ProgramHeader64;
-    type SectionHeader = SectionHeader64;
+    type ElfHeader<E: Endianness> = ElfHeader64<E>;
+    type ProgramHeader<E: Endianness> = ProgramHeader64<E>;
+    type SectionHeader<E: Endianness> = SectionHeader64<E>;
 
     fn elf_class() -> ElfClass {
         ElfClass::Elf64
@@ -28,9 +30,9 @@ impl ElfType for ElfType64 {
 pub enum ElfType32 {}
 
 impl ElfType for ElfType32 {
-    type ElfHeader = ElfHeader32;
-    type ProgramHeader = ProgramHeader32;
-    type SectionHeader = SectionHeader32;
+    type ElfHeader<E: Endianness> = ElfHeader32<E>;
+    type ProgramHeader<E: Endianness> = ProgramHeader32<E>;
+    type SectionHeader<E: Endianness> = SectionHeader32<E>;
 
     fn elf_class() -> ElfClass {
         ElfClass::Elf32
@@ -49,7 +51,7 @@ impl<'a, ET: ElfType> Elf<'a, ET> {
             return Err(Error::InvalidMagic);
         }
 
-        if buf.len() < size_of::<ET::ElfHeader>() {
+        if buf.len() < size_of::<ET::ElfHeader<LittleEndian>>() {
             return Err(Error::BufferTooShort);
         }
 
@@ -62,6 +64,21 @@ impl<'a, ET: ElfType> Elf<'a, ET> {
             return Err(Error::InvalidClass);
         }
 
+        if let ElfEndian::Unknown(n) = elf.elf_header().endianness() {
+            return Err(Error::InvalidEndian(n));
+        }
+
+        let (ph_num, sh_num) = elf.header_table_nums();
+        let ph_entsize = elf.elf_header().program_header_entry_size();
+        if ph_num != 0 && (ph_entsize as usize) < size_of::<ET::ProgramHeader<LittleEndian>>() {
+            return Err(Error::InvalidProgramHeaderEntrySize(ph_entsize));
+        }
+
+        let sh_entsize = elf.elf_header().section_header_entry_size();
+        if sh_num != 0 && (sh_entsize as usize) < size_of::<ET::SectionHeader<LittleEndian>>() {
+            return Err(Error::InvalidSectionHeaderEntrySize(sh_entsize));
+        }
+
         Ok(elf)
     }
 
@@ -69,46 +86,194 @@ impl<'a, ET: ElfType> Elf<'a, ET> {
         self.0
     }
 
-    pub fn elf_header_raw(&self) -> &ET::ElfHeader {
-        unsafe { &*(self.content().as_ptr() as *const ET::ElfHeader) }
+    /// Returns the ELF header, or `None` if `E` does not match the byte
+    /// order of the file.
+    pub fn elf_header_raw<E: Endianness>(&self) -> Option<&'a ET::ElfHeader<E>> {
+        let header = self.elf_header_endian::<E>();
+        (header.endianness() == E::ENDIAN).then_some(header)
+    }
+
+    fn elf_header_endian<E: Endianness>(&self) -> &'a ET::ElfHeader<E> {
+        raw_nth(self.0, 0).expect("buffer holds an ELF header")
     }
 
-    pub fn program_headers_raw(&self) -> Option<&'a [ET::ProgramHeader]> {
+    /// Returns the program header table, or `None` if it is out of bounds,
+    /// `E` does not match the byte order of the file, or `e_phentsize` is
+    /// larger than the program header structure.
+    pub fn program_headers_raw<E: Endianness>(&self) -> Option<&'a [ET::ProgramHeader<E>]> {
+        if self.elf_header().endianness() != E::ENDIAN
+            || self.elf_header().program_header_entry_size() as usize
+                != size_of::<ET::ProgramHeader<E>>()
+        {
+            return None;
+        }
         let ph_off = self.elf_header().program_header_offset() as usize;
-        let ph_num = self.elf_header().program_header_entry_num() as usize;
-        let ph_top = ph_off.saturating_add(ph_num.saturating_mul(size_of::<ET::ProgramHeader>()));
-        self.content()
-            .get(ph_off..ph_top)
-            .map(|mem| unsafe { from_raw_parts(mem.as_ptr() as *const ET::ProgramHeader, ph_num) })
+        let ph_num = self.program_header_num::<E>();
+        raw_slice(self.0.get(ph_off..)?, ph_num)
+    }
+
+    /// The `index`-th entry of a header table at `offset` holding `num`
+    /// entries, `entsize` bytes apart. The whole table must be in bounds.
+    fn table_nth<T: Pod>(
+        &self,
+        offset: usize,
+        num: usize,
+        entsize: usize,
+        index: usize,
+    ) -> Option<&'a T> {
+        if index >= num {
+            return None;
+        }
+        let top = offset.checked_add(num.checked_mul(entsize)?)?;
+        let table = self.0.get(offset..top)?;
+        raw_nth(table.get(index * entsize..)?, 0)
+    }
+
+    /// Section header 0, which holds the real section header count,
+    /// `.shstrtab` index and program header count when they do not fit in
+    /// the ELF header.
+    fn section_header_zero<E: Endianness>(&self) -> Option<&'a ET::SectionHeader<E>> {
+        match self.elf_header().section_header_offset() as usize {
+            0 => None,
+            sh_off => raw_nth(self.0.get(sh_off..)?, 0),
+        }
+    }
+
+    /// Number of program headers, following `PN_XNUM` extended numbering.
+    fn program_header_num<E: Endianness>(&self) -> usize {
+        match self.elf_header().program_header_entry_num() {
+            PN_XNUM => self
+                .section_header_zero::<E>()
+                .map_or(PN_XNUM as usize, |sh| sh.info() as usize),
+            n => n as usize,
+        }
+    }
+
+    /// Number of section headers. If it is 0xff00 or more, `e_shnum` is 0
+    /// and the real count is held in `sh_size` of section header 0.
+    fn section_header_num<E: Endianness>(&self) -> usize {
+        match self.elf_header().section_header_entry_num() {
+            0 => self
+                .section_header_zero::<E>()
+                .map_or(0, |sh| sh.size() as usize),
+            n => n as usize,
+        }
     }
 
-    pub fn program_header_iter(&self) -> ProgramHeaderIter {
+    /// Numbers of program and section headers, in the byte order of the
+    /// file.
+    fn header_table_nums(&self) -> (usize, usize) {
+        match self.elf_header().endianness() {
+            ElfEndian::BigEndian => (
+                self.program_header_num::<BigEndian>(),
+                self.section_header_num::<BigEndian>(),
+            ),
+            _ => (
+                self.program_header_num::<LittleEndian>(),
+                self.section_header_num::<LittleEndian>(),
+            ),
+        }
+    }
+
+    pub fn program_header_iter(&self) -> ProgramHeaderIter<'_> {
         ProgramHeaderIter::new(self)
     }
 
-    pub fn program_header_nth(&self, index: usize) -> Option<ProgramHeaderEntry> {
-        self.program_headers_raw()
-            .and_then(|s| s.get(index))
+    pub fn program_header_nth(&self, index: usize) -> Option<ProgramHeaderEntry<'_>> {
+        self.try_program_header_nth(index).ok()
+    }
+
+    pub fn try_program_header_nth(&self, index: usize) -> Result<ProgramHeaderEntry<'_>, Error> {
+        match self.elf_header().endianness() {
+            ElfEndian::BigEndian => self.try_program_header_nth_endian::<BigEndian>(index),
+            _ => self.try_program_header_nth_endian::<LittleEndian>(index),
+        }
+    }
+
+    fn try_program_header_nth_endian<E: Endianness>(
+        &self,
+        index: usize,
+    ) -> Result<ProgramHeaderEntry<'_>, Error> {
+        let ph_off = self.elf_header().program_header_offset() as usize;
+        let ph_num = self.program_header_num::<E>();
+        let ph_entsize = self.elf_header().program_header_entry_size() as usize;
+        if index >= ph_num {
+            return Err(Error::ProgramHeaderIndexOutOfRange { index, num: ph_num });
+        }
+        self.table_nth::<ET::ProgramHeader<E>>(ph_off, ph_num, ph_entsize, index)
             .map(|ph| ProgramHeaderEntry::new(self, ph))
+            .ok_or(Error::ProgramHeaderTableOutOfBounds {
+                offset: ph_off as u64,
+                size: (ph_num as u64).saturating_mul(ph_entsize as u64),
+            })
     }
 
-    pub fn section_headers_raw(&'a self) -> Option<&'a [ET::SectionHeader]> {
+    /// Returns the section header table, or `None` if it is out of bounds,
+    /// `E` does not match the byte order of the file, or `e_shentsize` is
+    /// larger than the section header structure.
+    pub fn section_headers_raw<E: Endianness>(&self) -> Option<&'a [ET::SectionHeader<E>]> {
+        if self.elf_header().endianness() != E::ENDIAN
+            || self.elf_header().section_header_entry_size() as usize
+                != size_of::<ET::SectionHeader<E>>()
+        {
+            return None;
+        }
         let sh_off = self.elf_header().section_header_offset() as usize;
-        let sh_num = self.elf_header().section_header_entry_num() as usize;
-        let sh_top = sh_off.saturating_add(sh_num.saturating_mul(size_of::<ET::SectionHeader>()));
-        self.content()
-            .get(sh_off..sh_top)
-            .map(|mem| unsafe { from_raw_parts(mem.as_ptr() as *const ET::SectionHeader, sh_num) })
+        let sh_num = self.section_header_num::<E>();
+        raw_slice(self.0.get(sh_off..)?, sh_num)
     }
 
-    pub fn section_header_iter(&self) -> SectionHeaderIter {
+    /// Checks the file layout against `policy`. Fails with
+    /// `Error::ValidationFailed` on the first finding the policy rejects.
+    pub fn validate(&self, policy: ValidationPolicy) -> Result<ValidationReport, Error> {
+        let report = self.validation_report();
+        report.check(policy)?;
+        Ok(report)
+    }
+
+    /// Checks the file layout, leaving it to the caller to judge the
+    /// findings.
+    pub fn validation_report(&self) -> ValidationReport {
+        let (ph_num, sh_num) = self.header_table_nums();
+        let layout = Layout {
+            ehsize: size_of::<ET::ElfHeader<LittleEndian>>(),
+            ph_num,
+            sh_num,
+        };
+        validate(self, layout)
+    }
+
+    pub fn section_header_iter(&self) -> SectionHeaderIter<'_> {
         SectionHeaderIter::new(self)
     }
 
-    pub fn section_header_nth(&self, index: usize) -> Option<SectionHeaderEntry> {
-        self.section_headers_raw()
-            .and_then(|s| s.get(index))
+    pub fn section_header_nth(&self, index: usize) -> Option<SectionHeaderEntry<'_>> {
+        self.try_section_header_nth(index).ok()
+    }
+
+    pub fn try_section_header_nth(&self, index: usize) -> Result<SectionHeaderEntry<'_>, Error> {
+        match self.elf_header().endianness() {
+            ElfEndian::BigEndian => self.try_section_header_nth_endian::<BigEndian>(index),
+            _ => self.try_section_header_nth_endian::<LittleEndian>(index),
+        }
+    }
+
+    fn try_section_header_nth_endian<E: Endianness>(
+        &self,
+        index: usize,
+    ) -> Result<SectionHeaderEntry<'_>, Error> {
+        let sh_off = self.elf_header().section_header_offset() as usize;
+        let sh_num = self.section_header_num::<E>();
+        let sh_entsize = self.elf_header().section_header_entry_size() as usize;
+        if index >= sh_num {
+            return Err(Error::SectionHeaderIndexOutOfRange { index, num: sh_num });
+        }
+        self.table_nth::<ET::SectionHeader<E>>(sh_off, sh_num, sh_entsize, index)
             .map(|sh| SectionHeaderEntry::new(self, sh))
+            .ok_or(Error::SectionHeaderTableOutOfBounds {
+                offset: sh_off as u64,
+                size: (sh_num as u64).saturating_mul(sh_entsize as u64),
+            })
     }
 }
 
@@ -117,24 +282,28 @@ impl<'a, ET: ElfType> ElfFile for Elf<'a, ET> {
         self.content()
     }
 
-    fn elf_header(&self) -> ElfHeader {
-        ElfHeader::new(self, self.elf_header_raw())
+    fn elf_header(&self) -> ElfHeader<'_> {
+        let inner: &dyn ElfHeaderRaw = match ElfEndian::from(self.0[EI_DATA]) {
+            ElfEndian::BigEndian => self.elf_header_endian::<BigEndian>(),
+            _ => self.elf_header_endian::<LittleEndian>(),
+        };
+        ElfHeader::new(self, inner)
     }
 
-    fn program_header_nth(&self, index: usize) -> Option<ProgramHeaderEntry> {
-        self.program_header_nth(index)
+    fn try_program_header_nth(&self, index: usize) -> Result<ProgramHeaderEntry<'_>, Error> {
+        self.try_program_header_nth(index)
     }
 
-    fn program_header_iter(&self) -> ProgramHeaderIter {
+    fn program_header_iter(&self) -> ProgramHeaderIter<'_> {
         self.program_header_iter()
     }
 
-    fn section_header_iter(&self) -> SectionHeaderIter {
+    fn section_header_iter(&self) -> SectionHeaderIter<'_> {
         self.section_header_iter()
     }
 
-    fn section_header_nth(&self, index: usize) -> Option<SectionHeaderEntry> {
-        self.section_header_nth(index)
+    fn try_section_header_nth(&self, index: usize) -> Result<SectionHeaderEntry<'_>, Error> {
+        self.try_section_header_nth(index)
     }
 }
 
@@ -142,7 +311,7 @@ pub type Elf32<'a> = Elf<'a, ElfType32>;
 pub type Elf64<'a> = Elf<'a, ElfType64>;
 
 impl<'a, ET: ElfType> fmt::Debug for Elf<'a, ET> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ELF Buffer")
             .field("Memory Location", &self.content().as_ptr())
             .field("Buffer Size", &self.content().len())
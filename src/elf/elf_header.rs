@@ -25,7 +25,7 @@ impl<'a> ops::Deref for ElfHeader<'a> {
 }
 
 impl<'a> fmt::Debug for ElfHeader<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ELF Header")
             .field("Class", &self.class())
             .field("Endianness", &self.endianness())
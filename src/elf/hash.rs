@@ -0,0 +1,274 @@
+use super::{segment_tail, ElfFile, StringTable, SymbolEntry, SymbolIter, VersymTable};
+use crate::dynamic::DynamicTag;
+use crate::elf_header::{ElfClass, ElfEndian};
+use crate::endian::{read_u32, read_u64};
+use crate::section_header::SectionType;
+use crate::symbol::{SectionIndex, SymbolType};
+
+/// The `DT_GNU_HASH` hash function.
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter()
+        .fold(5381u32, |h, &c| h.wrapping_mul(33).wrapping_add(c as u32))
+}
+
+/// The `DT_HASH` hash function from the System V ABI.
+pub fn sysv_hash(name: &[u8]) -> u32 {
+    name.iter().fold(0u32, |h, &c| {
+        let h = (h << 4).wrapping_add(c as u32);
+        let g = h & 0xf000_0000;
+        (h ^ (g >> 24)) & !g
+    })
+}
+
+pub(crate) const STT_GNU_IFUNC: u8 = 10;
+
+/// The dynamic symbol table a hash table indexes.
+#[derive(Copy, Clone)]
+struct DynamicSymbols<'a> {
+    elf_file: &'a dyn ElfFile,
+    data: &'a [u8],
+    entsize: u64,
+    strtab: Option<StringTable<'a>>,
+    versym: Option<VersymTable<'a>>,
+}
+
+impl<'a> DynamicSymbols<'a> {
+    /// The `index`-th symbol, if it is a definition named `name` that the
+    /// dynamic linker would bind to. Hidden versions are skipped, as for
+    /// references that do not name a version.
+    fn matching(&self, index: usize, name: &[u8]) -> Option<SymbolEntry<'a>> {
+        let symbol = SymbolIter::new(self.elf_file, self.data, self.strtab)
+            .with_entsize(self.entsize)
+            .with_versym(self.versym)
+            .nth(index)?;
+        let is_tls = symbol.sym_type() == SymbolType::STT_TLS;
+        let bindable = matches!(
+            symbol.sym_type(),
+            SymbolType::STT_NOTYPE
+                | SymbolType::STT_OBJECT
+                | SymbolType::STT_FUNC
+                | SymbolType::STT_COMMON
+                | SymbolType::STT_TLS
+                | SymbolType::OsSpecific(STT_GNU_IFUNC)
+        );
+        if !bindable
+            || symbol.section_index() == SectionIndex::SHN_UNDEF
+            || (symbol.value() == 0 && !is_tls)
+            || symbol.version_index().is_some_and(|v| v.is_hidden())
+            || symbol.symbol_name() != Some(name)
+        {
+            return None;
+        }
+        Some(symbol)
+    }
+}
+
+/// Finds the hash table with dynamic tag `tag`, together with the symbol
+/// and string tables it indexes. Uses `PT_DYNAMIC` where possible, falling
+/// back to a section of type `sh_type`.
+fn hash_table_parts<'a>(
+    elf_file: &'a dyn ElfFile,
+    tag: DynamicTag,
+    sh_type: SectionType,
+) -> Option<(&'a [u8], DynamicSymbols<'a>)> {
+    let from_dynamic = || {
+        let dynamic = elf_file.dynamic_iter()?;
+        let strtab = dynamic.string_table();
+        let mut table = None;
+        let mut symtab = None;
+        let mut syment = 0;
+        for entry in dynamic {
+            match entry.tag() {
+                t if t == tag => table = Some(entry.value()),
+                DynamicTag::DT_SYMTAB => symtab = Some(entry.value()),
+                DynamicTag::DT_SYMENT => syment = entry.value(),
+                _ => {}
+            }
+        }
+        let symbols = DynamicSymbols {
+            elf_file,
+            data: segment_tail(elf_file, symtab?)?,
+            entsize: syment,
+            strtab,
+            versym: VersymTable::find(elf_file),
+        };
+        Some((segment_tail(elf_file, table?)?, symbols))
+    };
+    let from_sections = || {
+        let section = elf_file
+            .section_header_iter()
+            .find(|s| s.sh_type() == sh_type)?;
+        let dynsym = elf_file.section_header_nth(section.link() as usize)?;
+        let symbols = DynamicSymbols {
+            elf_file,
+            data: dynsym.content()?,
+            entsize: dynsym.entsize(),
+            strtab: dynsym.linked_string_table(),
+            versym: VersymTable::find(elf_file),
+        };
+        Some((section.content()?, symbols))
+    };
+    from_dynamic().or_else(from_sections)
+}
+
+/// A `DT_GNU_HASH` table.
+pub struct GnuHashTable<'a> {
+    endian: ElfEndian,
+    bloom_bits: u32,
+    bucket_num: u32,
+    symbol_offset: u32,
+    bloom_num: u32,
+    bloom_shift: u32,
+    bloom: &'a [u8],
+    buckets: &'a [u8],
+    chain: &'a [u8],
+    symbols: DynamicSymbols<'a>,
+}
+
+impl<'a> GnuHashTable<'a> {
+    /// Finds the table through `PT_DYNAMIC`, or else the `SHT_GNU_HASH`
+    /// section. Keep the table for repeated lookups.
+    pub fn find(elf_file: &'a dyn ElfFile) -> Option<Self> {
+        let (data, symbols) =
+            hash_table_parts(elf_file, DynamicTag::DT_GNU_HASH, SectionType::SHT_GNU_HASH)?;
+        let elf_header = elf_file.elf_header();
+        let endian = elf_header.endianness();
+        let bloom_bits = match elf_header.class() {
+            ElfClass::Elf64 => 64,
+            _ => 32,
+        };
+        let bucket_num = read_u32(endian, data, 0)?;
+        let symbol_offset = read_u32(endian, data, 4)?;
+        let bloom_num = read_u32(endian, data, 8)?;
+        let bloom_shift = read_u32(endian, data, 12)?;
+        // The second bloom bit is `(hash >> bloom_shift) % bloom_bits`.
+        if bucket_num == 0 || bloom_num == 0 || bloom_shift >= bloom_bits {
+            return None;
+        }
+        let bloom_start = 16;
+        let buckets_start =
+            bloom_start + (bloom_num as usize).checked_mul(bloom_bits as usize / 8)?;
+        let chain_start = buckets_start + (bucket_num as usize).checked_mul(4)?;
+        Some(Self {
+            endian,
+            bloom_bits,
+            bucket_num,
+            symbol_offset,
+            bloom_num,
+            bloom_shift,
+            bloom: data.get(bloom_start..buckets_start)?,
+            buckets: data.get(buckets_start..chain_start)?,
+            chain: data.get(chain_start..)?,
+            symbols,
+        })
+    }
+
+    pub fn bucket_num(&self) -> u32 {
+        self.bucket_num
+    }
+
+    /// Index of the first dynamic symbol covered by the table.
+    pub fn symbol_offset(&self) -> u32 {
+        self.symbol_offset
+    }
+
+    /// Whether the bloom filter admits `hash`. A miss means no symbol with
+    /// that hash is defined.
+    pub fn bloom_filter_matches(&self, hash: u32) -> bool {
+        let bits = self.bloom_bits;
+        let index = ((hash / bits) % self.bloom_num) as usize;
+        let word = match bits {
+            64 => read_u64(self.endian, self.bloom, index * 8),
+            _ => read_u32(self.endian, self.bloom, index * 4).map(u64::from),
+        };
+        let mask = (1u64 << (hash % bits)) | (1u64 << ((hash >> self.bloom_shift) % bits));
+        word.is_some_and(|w| w & mask == mask)
+    }
+
+    /// Looks up the dynamic symbol `name` the way the dynamic linker does.
+    pub fn lookup(&self, name: &[u8]) -> Option<SymbolEntry<'a>> {
+        let hash = gnu_hash(name);
+        if !self.bloom_filter_matches(hash) {
+            return None;
+        }
+        let bucket = (hash % self.bucket_num) as usize;
+        let mut index = read_u32(self.endian, self.buckets, bucket * 4)?;
+        if index < self.symbol_offset {
+            return None;
+        }
+        loop {
+            let chain_index = (index - self.symbol_offset) as usize;
+            let chain_hash = read_u32(self.endian, self.chain, chain_index.checked_mul(4)?)?;
+            if hash | 1 == chain_hash | 1 {
+                if let Some(symbol) = self.symbols.matching(index as usize, name) {
+                    return Some(symbol);
+                }
+            }
+            if chain_hash & 1 != 0 {
+                return None;
+            }
+            index = index.checked_add(1)?;
+        }
+    }
+}
+
+/// A `DT_HASH` table.
+pub struct SysvHashTable<'a> {
+    endian: ElfEndian,
+    bucket_num: u32,
+    chain_num: u32,
+    buckets: &'a [u8],
+    chain: &'a [u8],
+    symbols: DynamicSymbols<'a>,
+}
+
+impl<'a> SysvHashTable<'a> {
+    /// Finds the table through `PT_DYNAMIC`, or else the `SHT_HASH` section.
+    /// Keep the table for repeated lookups.
+    pub fn find(elf_file: &'a dyn ElfFile) -> Option<Self> {
+        let (data, symbols) =
+            hash_table_parts(elf_file, DynamicTag::DT_HASH, SectionType::SHT_HASH)?;
+        let endian = elf_file.elf_header().endianness();
+        let bucket_num = read_u32(endian, data, 0)?;
+        let chain_num = read_u32(endian, data, 4)?;
+        if bucket_num == 0 {
+            return None;
+        }
+        let chain_start = 8 + (bucket_num as usize).checked_mul(4)?;
+        let chain_end = chain_start + (chain_num as usize).checked_mul(4)?;
+        Some(Self {
+            endian,
+            bucket_num,
+            chain_num,
+            buckets: data.get(8..chain_start)?,
+            chain: data.get(chain_start..chain_end)?,
+            symbols,
+        })
+    }
+
+    pub fn bucket_num(&self) -> u32 {
+        self.bucket_num
+    }
+
+    /// Number of dynamic symbols, which equals the number of chain entries.
+    pub fn symbol_num(&self) -> u32 {
+        self.chain_num
+    }
+
+    /// Looks up the dynamic symbol `name` the way the dynamic linker does.
+    pub fn lookup(&self, name: &[u8]) -> Option<SymbolEntry<'a>> {
+        let bucket = (sysv_hash(name) % self.bucket_num) as usize;
+        let mut index = read_u32(self.endian, self.buckets, bucket * 4)?;
+        // Bound the walk so that a cyclic chain cannot loop forever.
+        for _ in 0..self.chain_num {
+            if index == 0 {
+                return None;
+            }
+            if let Some(symbol) = self.symbols.matching(index as usize, name) {
+                return Some(symbol);
+            }
+            index = read_u32(self.endian, self.chain, (index as usize).checked_mul(4)?)?;
+        }
+        None
+    }
+}
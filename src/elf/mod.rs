@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod elf;
 pub use elf::{Elf32, Elf64};
 
@@ -10,10 +11,93 @@ pub use program_header::{ProgramHeaderEntry, ProgramHeaderIter};
 mod section_header;
 pub use section_header::{SectionHeaderEntry, SectionHeaderIter};
 
+mod symbol;
+pub use symbol::{SymbolEntry, SymbolIter};
+
+mod relocation;
+pub use relocation::{RelocationEntry, RelocationIter};
+
+mod dynamic;
+pub use dynamic::{DynamicEntry, DynamicIter, DynamicStringIter};
+
+mod note;
+pub use note::{NoteEntry, NoteIter};
+
+mod version;
+pub use version::{
+    VerdauxIter, VerdefEntry, VerdefIter, VernauxEntry, VernauxIter, VerneedEntry, VerneedIter,
+    VersymTable,
+};
+
+mod hash;
+pub use hash::{gnu_hash, sysv_hash, GnuHashTable, SysvHashTable};
+
+#[cfg(feature = "alloc")]
+mod section_name_index;
+#[cfg(feature = "alloc")]
+pub use section_name_index::SectionNameIndex;
+
+#[cfg(feature = "gimli")]
+mod dwarf;
+#[cfg(feature = "gimli")]
+pub use dwarf::{DwarfData, DwarfReader};
+
+#[cfg(feature = "symbolize")]
+mod symbolize;
+#[cfg(feature = "symbolize")]
+pub use symbolize::{Frame, Frames, Symbolizer};
+
+mod debug_link;
+pub use debug_link::{DebugAltLink, DebugLink};
+
+#[cfg(feature = "std")]
+mod debug_file;
+#[cfg(feature = "std")]
+pub use debug_file::{DebugFile, DebugFileResolver};
+
+mod string_table;
+pub use string_table::{StringTable, StringTableIter};
+
+mod validate;
+pub(crate) use validate::{validate, Layout};
+pub use validate::{
+    Finding, FindingKind, Severity, ValidationPolicy, ValidationReport, MAX_FINDINGS,
+};
+
+use crate::dynamic::DynamicTag;
+use crate::endian::Pod;
+use crate::note::GnuNote;
+use crate::program_header::ProgramType;
+use crate::section_header::{SectionType, SHN_XINDEX};
+use crate::Error;
+use core::mem::{align_of, size_of};
+use core::slice::from_raw_parts;
+
+/// Views the first `num` `T` sized entries of `data`, if they are in bounds.
+pub(crate) fn raw_slice<T: Pod>(data: &[u8], num: usize) -> Option<&[T]> {
+    assert_eq!(align_of::<T>(), 1);
+    let entries = data.get(..num.checked_mul(size_of::<T>())?)?;
+    // Sound for any address: `T` has alignment 1 and accepts any bytes.
+    Some(unsafe { from_raw_parts(entries.as_ptr() as *const T, num) })
+}
+
+/// Views the `index`-th `T` sized entry of `data`, if it is in bounds.
+pub(crate) fn raw_nth<T: Pod>(data: &[u8], index: usize) -> Option<&T> {
+    let start = index.checked_mul(size_of::<T>())?;
+    raw_slice(data.get(start..)?, 1)?.first()
+}
+
+/// The bytes from `vaddr` to the end of the file image of its `PT_LOAD`
+/// segment, for tables whose size the dynamic section does not give.
+pub(crate) fn segment_tail(elf_file: &dyn ElfFile, vaddr: u64) -> Option<&[u8]> {
+    let segment = elf_file.load_segment_at_vaddr(vaddr)?;
+    segment.content()?.get((vaddr - segment.vaddr()) as usize..)
+}
+
 pub trait ElfType {
-    type ElfHeader: crate::elf_header::ElfHeaderRaw;
-    type ProgramHeader: crate::program_header::ProgramHeaderRaw;
-    type SectionHeader: crate::section_header::SectionHeaderRaw;
+    type ElfHeader<E: crate::Endianness>: crate::elf_header::ElfHeaderRaw + Pod;
+    type ProgramHeader<E: crate::Endianness>: crate::program_header::ProgramHeaderRaw + Pod;
+    type SectionHeader<E: crate::Endianness>: crate::section_header::SectionHeaderRaw + Pod;
 
     fn elf_class() -> crate::elf_header::ElfClass;
 }
@@ -21,24 +105,284 @@ pub trait ElfType {
 pub trait ElfFile {
     fn content(&self) -> &[u8];
 
-    fn elf_header(&self) -> ElfHeader;
+    fn elf_header(&self) -> ElfHeader<'_>;
+
+    fn try_program_header_nth(&self, index: usize) -> Result<ProgramHeaderEntry<'_>, Error>;
 
-    fn program_header_nth(&self, index: usize) -> Option<ProgramHeaderEntry>;
+    fn program_header_nth(&self, index: usize) -> Option<ProgramHeaderEntry<'_>> {
+        self.try_program_header_nth(index).ok()
+    }
+
+    fn program_header_iter(&self) -> ProgramHeaderIter<'_>;
+
+    fn try_section_header_nth(&self, index: usize) -> Result<SectionHeaderEntry<'_>, Error>;
+
+    fn section_header_nth(&self, index: usize) -> Option<SectionHeaderEntry<'_>> {
+        self.try_section_header_nth(index).ok()
+    }
+
+    fn section_header_iter(&self) -> SectionHeaderIter<'_>;
 
-    fn program_header_iter(&self) -> ProgramHeaderIter;
+    fn try_shstr_section(&self) -> Result<SectionHeaderEntry<'_>, Error> {
+        let shstr_index = match self.elf_header().shstr_index() {
+            SHN_XINDEX => self.try_section_header_nth(0)?.link() as usize,
+            n => n as usize,
+        };
+        self.try_section_header_nth(shstr_index)
+    }
+
+    fn shstr_section(&self) -> Option<SectionHeaderEntry<'_>> {
+        self.try_shstr_section().ok()
+    }
+
+    /// The section name string table.
+    fn try_shstrtab(&self) -> Result<StringTable<'_>, Error> {
+        self.try_shstr_section()?
+            .try_content()
+            .map(StringTable::new)
+    }
+
+    fn shstrtab(&self) -> Option<StringTable<'_>> {
+        self.try_shstrtab().ok()
+    }
+
+    /// The string table of the first `SHT_SYMTAB` section, usually `.strtab`.
+    fn strtab(&self) -> Option<StringTable<'_>> {
+        self.section_header_iter()
+            .find(|s| s.sh_type() == SectionType::SHT_SYMTAB)?
+            .linked_string_table()
+    }
+
+    /// The dynamic string table, found through `DT_STRTAB` or else the string
+    /// table linked to the first `SHT_DYNSYM` section.
+    fn dynstr(&self) -> Option<StringTable<'_>> {
+        self.dynamic_iter()
+            .and_then(|d| d.string_table())
+            .or_else(|| {
+                self.section_header_iter()
+                    .find(|s| s.sh_type() == SectionType::SHT_DYNSYM)?
+                    .linked_string_table()
+            })
+    }
+
+    /// The first section named `name`. Walks the section headers; build a
+    /// `SectionNameIndex` for repeated lookups.
+    fn lookup_section(&self, name: &[u8]) -> Option<SectionHeaderEntry<'_>> {
+        let shstrtab = self.shstrtab()?;
+        self.section_header_iter()
+            .find(|s| shstrtab.get(s.name_off() as usize) == Ok(name))
+    }
 
-    fn section_header_nth(&self, index: usize) -> Option<SectionHeaderEntry>;
+    /// Builds an index of all section names.
+    #[cfg(feature = "alloc")]
+    fn section_name_index(&self) -> SectionNameIndex<'_>
+    where
+        Self: Sized,
+    {
+        SectionNameIndex::new(self)
+    }
 
-    fn section_header_iter(&self) -> SectionHeaderIter;
+    /// Loads the DWARF sections for reading with `gimli`.
+    #[cfg(feature = "gimli")]
+    fn dwarf_data(&self) -> Result<DwarfData<'_>, Error>
+    where
+        Self: Sized,
+    {
+        DwarfData::load(self)
+    }
 
-    fn shstr_section(&self) -> Option<SectionHeaderEntry> {
-        let shstr_index = self.elf_header().shstr_index() as usize;
-        self.section_header_nth(shstr_index)
+    /// Symbols of the first `SHT_SYMTAB` section.
+    fn symbol_iter(&self) -> Option<SymbolIter<'_>> {
+        self.section_header_iter()
+            .find(|s| s.sh_type() == SectionType::SHT_SYMTAB)?
+            .symbol_iter()
     }
 
-    fn lookup_section(&self, name: &[u8]) -> Option<SectionHeaderEntry> {
+    /// Symbols of the first `SHT_DYNSYM` section.
+    fn dynamic_symbol_iter(&self) -> Option<SymbolIter<'_>> {
         self.section_header_iter()
-            .find(|s| s.section_name() == Some(name))
+            .find(|s| s.sh_type() == SectionType::SHT_DYNSYM)?
+            .symbol_iter()
+    }
+
+    /// The `DT_GNU_HASH` table, found through `PT_DYNAMIC` or else the
+    /// `SHT_GNU_HASH` section.
+    fn gnu_hash_table(&self) -> Option<GnuHashTable<'_>>
+    where
+        Self: Sized,
+    {
+        GnuHashTable::find(self)
+    }
+
+    /// The `DT_HASH` table, found through `PT_DYNAMIC` or else the `SHT_HASH`
+    /// section.
+    fn sysv_hash_table(&self) -> Option<SysvHashTable<'_>>
+    where
+        Self: Sized,
+    {
+        SysvHashTable::find(self)
+    }
+
+    /// The `.gnu.version` table, found through the `SHT_GNU_versym` section
+    /// or else `DT_VERSYM`.
+    fn versym_table(&self) -> Option<VersymTable<'_>>
+    where
+        Self: Sized,
+    {
+        VersymTable::find(self)
+    }
+
+    /// Version definitions, found through the `SHT_GNU_verdef` section or
+    /// else `DT_VERDEF`.
+    fn verdef_iter(&self) -> Option<VerdefIter<'_>>
+    where
+        Self: Sized,
+    {
+        VerdefIter::find(self)
+    }
+
+    /// Versions required from other libraries, found through the
+    /// `SHT_GNU_verneed` section or else `DT_VERNEED`.
+    fn verneed_iter(&self) -> Option<VerneedIter<'_>>
+    where
+        Self: Sized,
+    {
+        VerneedIter::find(self)
+    }
+
+    /// Looks up a defined dynamic symbol through the GNU hash table, or the
+    /// System V hash table if there is none, without scanning `.dynsym`.
+    ///
+    /// A convenience for one-off lookups: the table is found again on every
+    /// call. Keep a `GnuHashTable` or `SysvHashTable` for repeated lookups.
+    fn lookup_dynamic_symbol(&self, name: &[u8]) -> Option<SymbolEntry<'_>>
+    where
+        Self: Sized,
+    {
+        match self.gnu_hash_table() {
+            Some(table) => table.lookup(name),
+            None => self.sysv_hash_table()?.lookup(name),
+        }
+    }
+
+    /// The `PT_LOAD` segment whose memory image contains `vaddr`.
+    fn load_segment_at_vaddr(&self, vaddr: u64) -> Option<ProgramHeaderEntry<'_>> {
+        self.program_header_iter()
+            .filter(|p| p.ph_type() == ProgramType::LOAD)
+            .find(|p| vaddr >= p.vaddr() && vaddr - p.vaddr() < p.memsz())
+    }
+
+    /// Translates a virtual address to a file offset. Addresses in the
+    /// zero-filled part of a segment beyond `p_filesz` have no file offset.
+    fn vaddr_to_offset(&self, vaddr: u64) -> Option<u64> {
+        let segment = self.load_segment_at_vaddr(vaddr)?;
+        let delta = vaddr - segment.vaddr();
+        if delta >= segment.filesz() {
+            return None;
+        }
+        segment.offset().checked_add(delta)
+    }
+
+    /// Translates a file offset to the virtual address it is loaded at.
+    fn offset_to_vaddr(&self, offset: u64) -> Option<u64> {
+        self.program_header_iter()
+            .filter(|p| p.ph_type() == ProgramType::LOAD)
+            .find(|p| offset >= p.offset() && offset - p.offset() < p.filesz())
+            .and_then(|p| p.vaddr().checked_add(offset - p.offset()))
+    }
+
+    /// Bytes of the file loaded at `vaddr`. Returns `None` unless the whole
+    /// range lies in the file image of one `PT_LOAD` segment; use
+    /// `read_at_vaddr_into` for ranges reaching into its zero-filled tail.
+    fn read_at_vaddr(&self, vaddr: u64, len: usize) -> Option<&[u8]> {
+        let segment = self.load_segment_at_vaddr(vaddr)?;
+        let start = (vaddr - segment.vaddr()) as usize;
+        segment.content()?.get(start..start.checked_add(len)?)
+    }
+
+    /// Copies the memory image at `vaddr` into `buf`, zero-filling the part
+    /// of the segment between `p_filesz` and `p_memsz`. The whole range must
+    /// lie in one `PT_LOAD` segment.
+    fn read_at_vaddr_into(&self, vaddr: u64, buf: &mut [u8]) -> Option<()> {
+        let segment = self.load_segment_at_vaddr(vaddr)?;
+        let start = vaddr - segment.vaddr();
+        if segment.memsz() - start < buf.len() as u64 {
+            return None;
+        }
+        let content = segment.content()?;
+        let file_part = content.get(start as usize..).unwrap_or(&[]);
+        let copied = file_part.len().min(buf.len());
+        buf[..copied].copy_from_slice(&file_part[..copied]);
+        buf[copied..].fill(0);
+        Some(())
+    }
+
+    /// Entries of the dynamic section, found through the `PT_DYNAMIC` segment
+    /// or else the first `SHT_DYNAMIC` section.
+    fn dynamic_iter(&self) -> Option<DynamicIter<'_>> {
+        self.program_header_iter()
+            .find(|p| p.ph_type() == ProgramType::DYNAMIC)
+            .and_then(|p| p.dynamic_iter())
+            .or_else(|| {
+                self.section_header_iter()
+                    .find(|s| s.sh_type() == SectionType::SHT_DYNAMIC)?
+                    .dynamic_iter()
+            })
+    }
+
+    /// Names of the shared libraries listed in `DT_NEEDED` entries.
+    fn needed_libraries(&self) -> DynamicStringIter<'_> {
+        DynamicStringIter::new(self.dynamic_iter(), DynamicTag::DT_NEEDED)
+    }
+
+    fn soname(&self) -> Option<&[u8]> {
+        self.dynamic_iter()?
+            .find(|d| d.tag() == DynamicTag::DT_SONAME)?
+            .string_value()
+    }
+
+    fn runpath(&self) -> Option<&[u8]> {
+        self.dynamic_iter()?
+            .find(|d| d.tag() == DynamicTag::DT_RUNPATH)?
+            .string_value()
+    }
+
+    fn rpath(&self) -> Option<&[u8]> {
+        self.dynamic_iter()?
+            .find(|d| d.tag() == DynamicTag::DT_RPATH)?
+            .string_value()
+    }
+
+    /// The GNU build-id, from the `.note.gnu.build-id` section or else any
+    /// `PT_NOTE` segment.
+    fn build_id(&self) -> Option<&[u8]> {
+        fn find_build_id<'a>(mut notes: impl Iterator<Item = NoteEntry<'a>>) -> Option<&'a [u8]> {
+            notes.find_map(|n| match n.gnu_note() {
+                Some(GnuNote::BuildId(id)) => Some(id),
+                _ => None,
+            })
+        }
+
+        self.lookup_section(b".note.gnu.build-id")
+            .and_then(|s| find_build_id(s.note_iter()?))
+            .or_else(|| {
+                find_build_id(
+                    self.program_header_iter()
+                        .filter_map(|p| p.note_iter())
+                        .flatten(),
+                )
+            })
+    }
+
+    /// The separate debug file named by `.gnu_debuglink`.
+    fn debug_link(&self) -> Option<DebugLink<'_>> {
+        let section = self.lookup_section(b".gnu_debuglink")?;
+        DebugLink::parse(self.elf_header().endianness(), section.content()?)
+    }
+
+    /// The supplementary debug file named by `.gnu_debugaltlink`.
+    fn debug_alt_link(&self) -> Option<DebugAltLink<'_>> {
+        DebugAltLink::parse(self.lookup_section(b".gnu_debugaltlink")?.content()?)
     }
 
     fn entry_point(&self) -> u64 {
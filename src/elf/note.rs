@@ -0,0 +1,100 @@
+use crate::elf_header::{ElfClass, ElfEndian};
+use crate::endian::read_u32;
+use crate::note::{align_up, GnuNote, ELF_NOTE_GNU};
+use core::fmt;
+
+pub struct NoteEntry<'a> {
+    endian: ElfEndian,
+    class: ElfClass,
+    name: &'a [u8],
+    n_type: u32,
+    desc: &'a [u8],
+}
+
+impl<'a> NoteEntry<'a> {
+    /// Owner of the note, without its NUL terminator.
+    pub fn name(&self) -> &'a [u8] {
+        self.name.split(|&x| x == b'\0').next().unwrap_or(self.name)
+    }
+
+    /// Type of the note, whose meaning depends on its owner.
+    pub fn n_type(&self) -> u32 {
+        self.n_type
+    }
+
+    pub fn desc(&self) -> &'a [u8] {
+        self.desc
+    }
+
+    /// Decodes the note if it is one of the well-known `"GNU"` notes.
+    pub fn gnu_note(&self) -> Option<GnuNote<'a>> {
+        if self.name() != ELF_NOTE_GNU {
+            return None;
+        }
+        GnuNote::parse(self.endian, self.class, self.n_type, self.desc)
+    }
+}
+
+impl<'a> fmt::Debug for NoteEntry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = core::str::from_utf8(self.name()).unwrap_or("");
+        f.debug_struct("Note")
+            .field("name", &name)
+            .field("type", &self.n_type())
+            .field("desc", &self.desc())
+            .finish()
+    }
+}
+
+/// Iterates the notes of a `SHT_NOTE` section or `PT_NOTE` segment.
+pub struct NoteIter<'a> {
+    endian: ElfEndian,
+    class: ElfClass,
+    data: &'a [u8],
+    align: usize,
+    offset: usize,
+}
+
+impl<'a> NoteIter<'a> {
+    /// Creates an iterator over `data`. Names and descriptors are padded to
+    /// 8 bytes if `align` is 8, and to 4 bytes otherwise.
+    pub fn new(endian: ElfEndian, class: ElfClass, data: &'a [u8], align: u64) -> Self {
+        let align = match align {
+            8 => 8,
+            _ => 4,
+        };
+        Self {
+            endian,
+            class,
+            data,
+            align,
+            offset: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for NoteIter<'a> {
+    type Item = NoteEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let namesz = read_u32(self.endian, self.data, self.offset)? as usize;
+        let descsz = read_u32(self.endian, self.data, self.offset + 4)? as usize;
+        let n_type = read_u32(self.endian, self.data, self.offset + 8)?;
+
+        let name_start = self.offset + 12;
+        let name_end = name_start.checked_add(namesz)?;
+        let name = self.data.get(name_start..name_end)?;
+        let desc_start = align_up(name_end, self.align);
+        let desc_end = desc_start.checked_add(descsz)?;
+        let desc = self.data.get(desc_start..desc_end)?;
+        self.offset = align_up(desc_end, self.align);
+
+        Some(NoteEntry {
+            endian: self.endian,
+            class: self.class,
+            name,
+            n_type,
+            desc,
+        })
+    }
+}
@@ -1,5 +1,5 @@
-use super::ElfFile;
-use crate::ProgramHeaderRaw;
+use super::{DynamicIter, ElfFile, NoteIter};
+use crate::{Error, ProgramHeaderRaw, ProgramType};
 use core::fmt;
 use core::ops;
 
@@ -14,10 +14,48 @@ impl<'a> ProgramHeaderEntry<'a> {
     }
 
     pub fn content(&self) -> Option<&'a [u8]> {
+        self.try_content().ok()
+    }
+
+    pub fn try_content(&self) -> Result<&'a [u8], Error> {
         let offset = self.inner.offset() as usize;
         let size = self.inner.filesz() as usize;
         let top = offset.saturating_add(size);
-        self.elf_file.content().get(offset..top)
+        self.elf_file
+            .content()
+            .get(offset..top)
+            .ok_or(Error::SegmentOutOfBounds {
+                offset: self.inner.offset(),
+                size: self.inner.filesz(),
+            })
+    }
+
+    /// `p_type`, with processor-specific types resolved for the target
+    /// machine of the file.
+    pub fn resolved_type(&self) -> ProgramType {
+        ProgramType::new(self.elf_file.elf_header().machine(), self.inner.raw_type())
+    }
+
+    /// Iterates the entries of a `PT_DYNAMIC` segment.
+    pub fn dynamic_iter(&self) -> Option<DynamicIter<'a>> {
+        match self.inner.ph_type() {
+            ProgramType::DYNAMIC => Some(DynamicIter::new(self.elf_file, self.content()?, None)),
+            _ => None,
+        }
+    }
+
+    /// Iterates the notes of a `PT_NOTE` segment.
+    pub fn note_iter(&self) -> Option<NoteIter<'a>> {
+        if self.inner.ph_type() != ProgramType::NOTE {
+            return None;
+        }
+        let elf_header = self.elf_file.elf_header();
+        Some(NoteIter::new(
+            elf_header.endianness(),
+            elf_header.class(),
+            self.content()?,
+            self.inner.align(),
+        ))
     }
 }
 
@@ -29,9 +67,9 @@ impl<'a> ops::Deref for ProgramHeaderEntry<'a> {
 }
 
 impl<'a> fmt::Debug for ProgramHeaderEntry<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Program Header")
-            .field("type", &self.ph_type())
+            .field("type", &self.resolved_type())
             .field("flags", &self.flags())
             .field("offset", &self.offset())
             .field("vaddr", &self.vaddr())
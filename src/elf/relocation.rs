@@ -0,0 +1,162 @@
+use super::{raw_nth, ElfFile, SymbolEntry};
+use crate::elf_header::{ElfClass, ElfEndian, ElfMachine};
+use crate::endian::{BigEndian, Endianness, LittleEndian};
+use crate::relocation::{Rel32, Rel64, Rela32, Rela64, RelocationRaw, RelocationType};
+use core::fmt;
+use core::ops;
+
+pub struct RelocationEntry<'a> {
+    elf_file: &'a dyn ElfFile,
+    symtab_index: usize,
+    inner: &'a dyn RelocationRaw,
+}
+
+impl<'a> ops::Deref for RelocationEntry<'a> {
+    type Target = dyn RelocationRaw + 'a;
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<'a> RelocationEntry<'a> {
+    pub fn new(
+        elf_file: &'a dyn ElfFile,
+        symtab_index: usize,
+        inner: &'a dyn RelocationRaw,
+    ) -> Self {
+        Self {
+            elf_file,
+            symtab_index,
+            inner,
+        }
+    }
+
+    /// 64-bit MIPS does not pack `r_info` as a single word. It holds a 32-bit
+    /// symbol index followed by a special symbol byte and three type bytes,
+    /// see the "64-bit ELF Object File Specification" from MIPS.
+    fn is_mips64(&self) -> bool {
+        let elf_header = self.elf_file.elf_header();
+        elf_header.class() == ElfClass::Elf64
+            && matches!(
+                elf_header.machine(),
+                ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE
+            )
+    }
+
+    /// Symbol index of the relocation. Unlike the raw accessor, this accounts
+    /// for the 64-bit MIPS `r_info` layout.
+    pub fn sym_index(&self) -> u32 {
+        if !self.is_mips64() {
+            return self.inner.sym_index();
+        }
+        match self.elf_file.elf_header().endianness() {
+            ElfEndian::BigEndian => (self.inner.info() >> 32) as u32,
+            _ => self.inner.info() as u32,
+        }
+    }
+
+    /// Relocation type number. Unlike the raw accessor, this accounts for the
+    /// 64-bit MIPS `r_info` layout, returning its first type byte.
+    pub fn r_type(&self) -> u32 {
+        if !self.is_mips64() {
+            return self.inner.r_type();
+        }
+        match self.elf_file.elf_header().endianness() {
+            ElfEndian::BigEndian => (self.inner.info() & 0xff) as u32,
+            _ => (self.inner.info() >> 56) as u32,
+        }
+    }
+
+    /// Relocation type decoded for the target machine of the file.
+    pub fn relocation_type(&self) -> RelocationType {
+        RelocationType::new(self.elf_file.elf_header().machine(), self.r_type())
+    }
+
+    /// The symbol this relocation refers to, looked up in the symbol table
+    /// linked to its relocation section. `None` for relocations without a
+    /// symbol (index 0).
+    pub fn symbol(&self) -> Option<SymbolEntry<'a>> {
+        match self.sym_index() {
+            0 => None,
+            i => self
+                .elf_file
+                .section_header_nth(self.symtab_index)?
+                .symbol_iter()?
+                .nth(i as usize),
+        }
+    }
+}
+
+impl<'a> fmt::Debug for RelocationEntry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Relocation")
+            .field("offset", &self.offset())
+            .field("type", &self.relocation_type())
+            .field("symbol index", &self.sym_index())
+            .field("addend", &self.addend())
+            .finish()
+    }
+}
+
+pub struct RelocationIter<'a> {
+    elf_file: &'a dyn ElfFile,
+    content: &'a [u8],
+    symtab_index: usize,
+    is_rela: bool,
+    index: usize,
+}
+
+impl<'a> RelocationIter<'a> {
+    pub fn new(
+        elf_file: &'a dyn ElfFile,
+        content: &'a [u8],
+        symtab_index: usize,
+        is_rela: bool,
+    ) -> Self {
+        Self {
+            elf_file,
+            content,
+            symtab_index,
+            is_rela,
+            index: 0,
+        }
+    }
+
+    fn relocation_raw_nth(&self, index: usize) -> Option<&'a dyn RelocationRaw> {
+        match self.elf_file.elf_header().endianness() {
+            ElfEndian::BigEndian => self.relocation_raw_nth_endian::<BigEndian>(index),
+            _ => self.relocation_raw_nth_endian::<LittleEndian>(index),
+        }
+    }
+
+    fn relocation_raw_nth_endian<E: Endianness>(
+        &self,
+        index: usize,
+    ) -> Option<&'a dyn RelocationRaw> {
+        let content = self.content;
+        match (self.elf_file.elf_header().class(), self.is_rela) {
+            (ElfClass::Elf64, true) => raw_nth::<Rela64<E>>(content, index).map(|r| r as _),
+            (ElfClass::Elf64, false) => raw_nth::<Rel64<E>>(content, index).map(|r| r as _),
+            (_, true) => raw_nth::<Rela32<E>>(content, index).map(|r| r as _),
+            (_, false) => raw_nth::<Rel32<E>>(content, index).map(|r| r as _),
+        }
+    }
+}
+
+impl<'a> Iterator for RelocationIter<'a> {
+    type Item = RelocationEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.relocation_raw_nth(self.index)
+            .map(|r| RelocationEntry::new(self.elf_file, self.symtab_index, r))
+            .map(|e| {
+                self.index += 1;
+                e
+            })
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n);
+        self.next()
+    }
+}
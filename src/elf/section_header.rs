@@ -1,5 +1,12 @@
-use super::ElfFile;
-use crate::section_header::SectionHeaderRaw;
+use super::{DynamicIter, ElfFile, NoteIter, RelocationIter, StringTable, SymbolIter, VersymTable};
+#[cfg(any(feature = "zlib", feature = "zstd"))]
+use crate::section_header::decompress;
+use crate::section_header::{
+    CompressionHeader, MachineSectionFlags, SectionHeaderFlags, SectionHeaderRaw, SectionType,
+};
+use crate::Error;
+#[cfg(any(feature = "zlib", feature = "zstd"))]
+use alloc::vec::Vec;
 use core::fmt;
 use core::ops;
 
@@ -21,28 +28,189 @@ impl<'a> SectionHeaderEntry<'a> {
     }
 
     pub fn content(&self) -> Option<&'a [u8]> {
+        self.try_content().ok()
+    }
+
+    pub fn try_content(&self) -> Result<&'a [u8], Error> {
         let offset = self.inner.offset() as usize;
         let size = self.inner.size() as usize;
         let top = offset.saturating_add(size);
-        self.elf_file.content().get(offset..top)
+        self.elf_file
+            .content()
+            .get(offset..top)
+            .ok_or(Error::SectionOutOfBounds {
+                offset: self.inner.offset(),
+                size: self.inner.size(),
+            })
+    }
+
+    /// The compression header of a `SHF_COMPRESSED` section, or of a
+    /// `.zdebug_*` section with a `"ZLIB"` prefix.
+    pub fn compression_header(&self) -> Option<CompressionHeader> {
+        self.try_compression_header().ok().flatten()
+    }
+
+    pub(crate) fn try_compression_header(&self) -> Result<Option<CompressionHeader>, Error> {
+        if self
+            .inner
+            .flags()
+            .contains(SectionHeaderFlags::SHF_COMPRESSED)
+        {
+            let elf_header = self.elf_file.elf_header();
+            return CompressionHeader::parse(
+                elf_header.endianness(),
+                elf_header.class(),
+                self.try_content()?,
+            )
+            .map(Some)
+            .ok_or(Error::InvalidCompressionHeader);
+        }
+        match self.section_name() {
+            Some(name) if name.starts_with(b".zdebug") => Ok(CompressionHeader::parse_zdebug(
+                self.try_content()?,
+                self.inner.addralign(),
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    /// The contents of the section, decompressed if it is compressed.
+    #[cfg(any(feature = "zlib", feature = "zstd"))]
+    pub fn decompressed_content(&self) -> Result<Vec<u8>, Error> {
+        let content = self.try_content()?;
+        match self.try_compression_header()? {
+            Some(header) => decompress(&header, &content[header.header_size..]),
+            None => Ok(content.to_vec()),
+        }
+    }
+
+    /// `sh_type`, with processor-specific types resolved for the target
+    /// machine of the file.
+    pub fn resolved_type(&self) -> SectionType {
+        SectionType::new(self.elf_file.elf_header().machine(), self.inner.raw_type())
+    }
+
+    /// The processor-specific bits of `sh_flags`, resolved for the target
+    /// machine of the file.
+    pub fn machine_flags(&self) -> MachineSectionFlags {
+        MachineSectionFlags::new(
+            self.elf_file.elf_header().machine(),
+            self.inner.flags().bits(),
+        )
     }
 
     pub fn section_name(&self) -> Option<&'a [u8]> {
-        let name_off = self.inner.name_off() as usize;
-        let shstr_content = self.elf_file.shstr_section()?.content()?;
-        shstr_content.get(name_off..)?.split(|&x| x == b'\0').next()
+        self.try_section_name().ok()
+    }
+
+    pub fn try_section_name(&self) -> Result<&'a [u8], Error> {
+        self.elf_file
+            .try_shstrtab()?
+            .get(self.inner.name_off() as usize)
+    }
+
+    /// The contents of a `SHT_STRTAB` section as a string table.
+    pub fn string_table(&self) -> Option<StringTable<'a>> {
+        match self.inner.sh_type() {
+            SectionType::SHT_STRTAB => self.content().map(StringTable::new),
+            _ => None,
+        }
+    }
+
+    /// The string table section given by `link`.
+    pub(crate) fn linked_string_table(&self) -> Option<StringTable<'a>> {
+        self.elf_file
+            .section_header_nth(self.inner.link() as usize)?
+            .string_table()
+    }
+
+    /// Iterates the symbols of a `SHT_SYMTAB` or `SHT_DYNSYM` section. Names
+    /// are resolved through the string table section given by `link`, and
+    /// dynamic symbols carry their `.gnu.version` entry.
+    pub fn symbol_iter(&self) -> Option<SymbolIter<'a>> {
+        let versym = match self.inner.sh_type() {
+            SectionType::SHT_SYMTAB => None,
+            SectionType::SHT_DYNSYM => VersymTable::find(self.elf_file),
+            _ => return None,
+        };
+        let strtab = self.linked_string_table();
+        let shndx = self
+            .elf_file
+            .section_header_iter()
+            .find(|s| {
+                s.sh_type() == SectionType::SHT_SYMTAB_SHNDX
+                    && self
+                        .elf_file
+                        .section_header_nth(s.link() as usize)
+                        .is_some_and(|linked| linked.is_same(self))
+            })
+            .and_then(|s| s.content());
+        Some(
+            SymbolIter::new(self.elf_file, self.content()?, strtab)
+                .with_entsize(self.inner.entsize())
+                .with_versym(versym)
+                .with_shndx(shndx),
+        )
+    }
+
+    /// Whether both entries view the same section header.
+    fn is_same(&self, other: &SectionHeaderEntry<'_>) -> bool {
+        core::ptr::eq(
+            self.inner as *const dyn SectionHeaderRaw as *const u8,
+            other.inner as *const dyn SectionHeaderRaw as *const u8,
+        )
+    }
+
+    /// Iterates the entries of a `SHT_DYNAMIC` section. Strings are resolved
+    /// through `DT_STRTAB`, or the string table section given by `link`.
+    pub fn dynamic_iter(&self) -> Option<DynamicIter<'a>> {
+        if self.inner.sh_type() != SectionType::SHT_DYNAMIC {
+            return None;
+        }
+        let strtab = self.linked_string_table();
+        Some(DynamicIter::new(self.elf_file, self.content()?, strtab))
+    }
+
+    /// Iterates the notes of a `SHT_NOTE` section.
+    pub fn note_iter(&self) -> Option<NoteIter<'a>> {
+        if self.inner.sh_type() != SectionType::SHT_NOTE {
+            return None;
+        }
+        let elf_header = self.elf_file.elf_header();
+        Some(NoteIter::new(
+            elf_header.endianness(),
+            elf_header.class(),
+            self.content()?,
+            self.inner.addralign(),
+        ))
+    }
+
+    /// Iterates the entries of a `SHT_REL` or `SHT_RELA` section. Symbols are
+    /// resolved through the symbol table section given by `link`.
+    pub fn relocation_iter(&self) -> Option<RelocationIter<'a>> {
+        let is_rela = match self.inner.sh_type() {
+            SectionType::SHT_REL => false,
+            SectionType::SHT_RELA => true,
+            _ => return None,
+        };
+        Some(RelocationIter::new(
+            self.elf_file,
+            self.content()?,
+            self.inner.link() as usize,
+            is_rela,
+        ))
     }
 }
 
 impl<'a> fmt::Debug for SectionHeaderEntry<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let sh_name = self
             .section_name()
             .and_then(|n| core::str::from_utf8(n).ok())
             .unwrap_or("");
         f.debug_struct("Section Header")
             .field("name", &sh_name)
-            .field("type", &self.sh_type())
+            .field("type", &self.resolved_type())
             .field("flags", &self.flags())
             .field("addr", &self.addr())
             .field("offset", &self.offset())
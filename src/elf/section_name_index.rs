@@ -0,0 +1,58 @@
+use super::{ElfFile, SectionHeaderEntry};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Section names sorted for binary search, so that repeated lookups do not
+/// walk the section header table.
+pub struct SectionNameIndex<'a> {
+    elf_file: &'a dyn ElfFile,
+    /// `(name, section index)`, sorted by name and then index.
+    names: Vec<(&'a [u8], usize)>,
+}
+
+impl<'a> SectionNameIndex<'a> {
+    /// Indexes every section whose name can be read from `.shstrtab`.
+    pub fn new(elf_file: &'a dyn ElfFile) -> Self {
+        let mut names = Vec::new();
+        if let Some(shstrtab) = elf_file.shstrtab() {
+            for (index, section) in elf_file.section_header_iter().enumerate() {
+                if let Ok(name) = shstrtab.get(section.name_off() as usize) {
+                    names.push((name, index));
+                }
+            }
+        }
+        names.sort_unstable();
+        Self { elf_file, names }
+    }
+
+    /// Index of the first section named `name`.
+    pub fn index_of(&self, name: &[u8]) -> Option<usize> {
+        let pos = self.names.partition_point(|&(n, _)| n < name);
+        match self.names.get(pos) {
+            Some(&(n, index)) if n == name => Some(index),
+            _ => None,
+        }
+    }
+
+    /// The first section named `name`, like `ElfFile::lookup_section`.
+    pub fn lookup(&self, name: &[u8]) -> Option<SectionHeaderEntry<'a>> {
+        self.elf_file.section_header_nth(self.index_of(name)?)
+    }
+
+    /// Number of indexed sections.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl<'a> fmt::Debug for SectionNameIndex<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Section Name Index")
+            .field("sections", &self.names.len())
+            .finish()
+    }
+}
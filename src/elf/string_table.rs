@@ -0,0 +1,96 @@
+use crate::Error;
+use core::ffi::CStr;
+use core::fmt;
+
+/// A string table, such as the contents of a `SHT_STRTAB` section or the
+/// `DT_STRTAB` table. Strings are referenced by their byte offset.
+#[derive(Copy, Clone)]
+pub struct StringTable<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> StringTable<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// The string at `offset`, without its NUL terminator. Fails if `offset`
+    /// is out of bounds or the string is not terminated within the table.
+    pub fn get(&self, offset: usize) -> Result<&'a [u8], Error> {
+        let tail = self.data.get(offset..).filter(|t| !t.is_empty()).ok_or(
+            Error::InvalidStringOffset {
+                offset: offset as u64,
+                size: self.data.len() as u64,
+            },
+        )?;
+        let len = tail
+            .iter()
+            .position(|&x| x == b'\0')
+            .ok_or(Error::UnterminatedString {
+                offset: offset as u64,
+            })?;
+        Ok(&tail[..len])
+    }
+
+    /// The string at `offset`, including its NUL terminator.
+    pub fn get_cstr(&self, offset: usize) -> Result<&'a CStr, Error> {
+        let len = self.get(offset)?.len();
+        let bytes = &self.data[offset..=offset + len];
+        Ok(CStr::from_bytes_with_nul(bytes).expect("string has a single NUL terminator"))
+    }
+
+    /// Iterates the strings of the table with their offsets. Bytes after the
+    /// last NUL terminator are skipped.
+    pub fn iter(&self) -> StringTableIter<'a> {
+        StringTableIter {
+            data: self.data,
+            offset: 0,
+        }
+    }
+}
+
+impl<'a> fmt::Debug for StringTable<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("String Table")
+            .field("size", &self.data.len())
+            .finish()
+    }
+}
+
+impl<'a> IntoIterator for StringTable<'a> {
+    type Item = (usize, &'a [u8]);
+    type IntoIter = StringTableIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct StringTableIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for StringTableIter<'a> {
+    type Item = (usize, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tail = self.data.get(self.offset..)?;
+        let len = tail.iter().position(|&x| x == b'\0')?;
+        let offset = self.offset;
+        self.offset += len + 1;
+        Some((offset, &tail[..len]))
+    }
+}
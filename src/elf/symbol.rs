@@ -0,0 +1,211 @@
+use super::{
+    raw_nth, ElfFile, SectionHeaderEntry, StringTable, VerdefIter, VerneedIter, VersymTable,
+};
+use crate::elf_header::{ElfClass, ElfEndian};
+use crate::endian::{read_u32, BigEndian, LittleEndian, Pod};
+use crate::symbol::{SectionIndex, Symbol32, Symbol64, SymbolRaw};
+use crate::version::{SymbolVersion, VersionFlags, VersionIndex};
+use core::fmt;
+use core::mem::size_of;
+use core::ops;
+
+pub struct SymbolEntry<'a> {
+    elf_file: &'a dyn ElfFile,
+    strtab: Option<StringTable<'a>>,
+    inner: &'a dyn SymbolRaw,
+    version: Option<VersionIndex>,
+    extended_index: Option<u32>,
+}
+
+impl<'a> ops::Deref for SymbolEntry<'a> {
+    type Target = dyn SymbolRaw + 'a;
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<'a> SymbolEntry<'a> {
+    pub fn new(
+        elf_file: &'a dyn ElfFile,
+        strtab: Option<StringTable<'a>>,
+        inner: &'a dyn SymbolRaw,
+    ) -> Self {
+        Self {
+            elf_file,
+            strtab,
+            inner,
+            version: None,
+            extended_index: None,
+        }
+    }
+
+    /// Name of the symbol, looked up in the string table linked to its
+    /// symbol table section.
+    pub fn symbol_name(&self) -> Option<&'a [u8]> {
+        self.strtab?.get(self.inner.name_off() as usize).ok()
+    }
+
+    /// The `.gnu.version` entry of a dynamic symbol.
+    pub fn version_index(&self) -> Option<VersionIndex> {
+        self.version
+    }
+
+    /// The version the symbol is defined with, or required at if it is
+    /// imported. Local and unversioned global symbols have none.
+    pub fn version(&self) -> Option<SymbolVersion<'a>> {
+        let version = self.version.filter(|v| !v.is_local() && !v.is_global())?;
+        let index = version.index();
+        let hidden = version.is_hidden();
+        let defined = VerdefIter::find(self.elf_file).and_then(|mut defs| {
+            defs.find(|d| d.index() == index && !d.flags().contains(VersionFlags::VER_FLG_BASE))
+        });
+        if let Some(def) = defined {
+            return Some(SymbolVersion {
+                name: def.name()?,
+                hidden,
+                file: None,
+            });
+        }
+        VerneedIter::find(self.elf_file)?.find_map(|need| {
+            let aux = need.aux_iter().find(|a| a.index() == index)?;
+            Some(SymbolVersion {
+                name: aux.name()?,
+                hidden,
+                file: need.file(),
+            })
+        })
+    }
+
+    /// Index of the section this symbol is defined in. `SHN_XINDEX` is
+    /// resolved through the `SHT_SYMTAB_SHNDX` section of the symbol table,
+    /// and is `None` without one.
+    pub fn section_header_index(&self) -> Option<usize> {
+        match self.inner.section_index() {
+            SectionIndex::Index(i) => Some(i as usize),
+            SectionIndex::SHN_XINDEX => self.extended_index.map(|i| i as usize),
+            _ => None,
+        }
+    }
+
+    /// The section this symbol is defined in, if it refers to a regular
+    /// section index.
+    pub fn section(&self) -> Option<SectionHeaderEntry<'a>> {
+        self.elf_file
+            .section_header_nth(self.section_header_index()?)
+    }
+}
+
+impl<'a> fmt::Debug for SymbolEntry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sym_name = self
+            .symbol_name()
+            .and_then(|n| core::str::from_utf8(n).ok())
+            .unwrap_or("");
+        f.debug_struct("Symbol")
+            .field("name", &sym_name)
+            .field("value", &self.value())
+            .field("size", &self.size())
+            .field("type", &self.sym_type())
+            .field("binding", &self.binding())
+            .field("visibility", &self.visibility())
+            .field("section index", &self.section_index())
+            .field("version", &self.version())
+            .finish()
+    }
+}
+
+pub struct SymbolIter<'a> {
+    elf_file: &'a dyn ElfFile,
+    content: &'a [u8],
+    strtab: Option<StringTable<'a>>,
+    versym: Option<VersymTable<'a>>,
+    /// The `SHT_SYMTAB_SHNDX` entries of the symbols.
+    shndx: Option<&'a [u8]>,
+    entsize: usize,
+    index: usize,
+}
+
+impl<'a> SymbolIter<'a> {
+    pub fn new(
+        elf_file: &'a dyn ElfFile,
+        content: &'a [u8],
+        strtab: Option<StringTable<'a>>,
+    ) -> Self {
+        Self {
+            elf_file,
+            content,
+            strtab,
+            versym: None,
+            shndx: None,
+            entsize: 0,
+            index: 0,
+        }
+    }
+
+    /// Sets the distance between symbols, usually `sh_entsize`. Sizes
+    /// smaller than the symbol structure, such as 0, are ignored.
+    pub fn with_entsize(mut self, entsize: u64) -> Self {
+        self.entsize = entsize as usize;
+        self
+    }
+
+    /// Attaches the `.gnu.version` entries of the symbols.
+    pub(crate) fn with_versym(mut self, versym: Option<VersymTable<'a>>) -> Self {
+        self.versym = versym;
+        self
+    }
+
+    /// Attaches the `SHT_SYMTAB_SHNDX` entries of the symbols.
+    pub(crate) fn with_shndx(mut self, shndx: Option<&'a [u8]>) -> Self {
+        self.shndx = shndx;
+        self
+    }
+
+    fn symbol_raw_nth_sized<T: SymbolRaw + Pod>(&self, index: usize) -> Option<&'a T> {
+        let entsize = self.entsize.max(size_of::<T>());
+        raw_nth(self.content.get(index.checked_mul(entsize)?..)?, 0)
+    }
+
+    fn symbol_raw_nth(&self, index: usize) -> Option<&'a dyn SymbolRaw> {
+        let elf_header = self.elf_file.elf_header();
+        match (elf_header.class(), elf_header.endianness()) {
+            (ElfClass::Elf64, ElfEndian::BigEndian) => self
+                .symbol_raw_nth_sized::<Symbol64<BigEndian>>(index)
+                .map(|s| s as _),
+            (ElfClass::Elf64, _) => self
+                .symbol_raw_nth_sized::<Symbol64<LittleEndian>>(index)
+                .map(|s| s as _),
+            (_, ElfEndian::BigEndian) => self
+                .symbol_raw_nth_sized::<Symbol32<BigEndian>>(index)
+                .map(|s| s as _),
+            _ => self
+                .symbol_raw_nth_sized::<Symbol32<LittleEndian>>(index)
+                .map(|s| s as _),
+        }
+    }
+}
+
+impl<'a> Iterator for SymbolIter<'a> {
+    type Item = SymbolEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.symbol_raw_nth(self.index)
+            .map(|s| SymbolEntry {
+                version: self.versym.and_then(|v| v.get(self.index)),
+                extended_index: self.shndx.and_then(|shndx| {
+                    let endian = self.elf_file.elf_header().endianness();
+                    read_u32(endian, shndx, self.index.checked_mul(4)?)
+                }),
+                ..SymbolEntry::new(self.elf_file, self.strtab, s)
+            })
+            .map(|e| {
+                self.index += 1;
+                e
+            })
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n);
+        self.next()
+    }
+}
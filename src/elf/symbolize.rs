@@ -0,0 +1,241 @@
+use super::dwarf::{gimli_endian, load_sections};
+use super::hash::STT_GNU_IFUNC;
+use super::ElfFile;
+use crate::symbol::{SectionIndex, SymbolType};
+use crate::Error;
+use alloc::borrow::Cow;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops;
+use core::slice;
+use gimli::RunTimeEndian;
+use stable_deref_trait::{CloneStableDeref, StableDeref};
+
+/// Section contents borrowed from the file, or owned if they were
+/// decompressed.
+#[derive(Clone)]
+enum SectionBytes<'a> {
+    Borrowed(&'a [u8]),
+    Owned(Rc<[u8]>),
+}
+
+impl<'a> ops::Deref for SectionBytes<'a> {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        match self {
+            SectionBytes::Borrowed(data) => data,
+            SectionBytes::Owned(data) => data,
+        }
+    }
+}
+
+// Sound: neither a borrow nor an `Rc` moves its bytes when it is moved or
+// cloned.
+unsafe impl<'a> StableDeref for SectionBytes<'a> {}
+unsafe impl<'a> CloneStableDeref for SectionBytes<'a> {}
+
+impl<'a> fmt::Debug for SectionBytes<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Section Bytes")
+            .field("size", &self.len())
+            .finish()
+    }
+}
+
+type Reader<'a> = gimli::EndianReader<RunTimeEndian, SectionBytes<'a>>;
+
+/// A symbol that addresses without debug info are attributed to.
+struct Symbol<'a> {
+    address: u64,
+    size: u64,
+    name: &'a [u8],
+}
+
+/// A function an address lies in, and where in its source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// The name as recorded in the file, without demangling.
+    pub function: Option<String>,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+}
+
+/// The frames of a symbolized address, innermost first. Each frame but the
+/// last was inlined into the one after it, and the last is the function
+/// the address lies in. Empty if nothing is known about the address.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frames {
+    frames: Vec<Frame>,
+}
+
+impl Frames {
+    pub fn iter(&self) -> slice::Iter<'_, Frame> {
+        self.frames.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The frame of the function the address lies in.
+    pub fn outermost(&self) -> Option<&Frame> {
+        self.frames.last()
+    }
+}
+
+impl IntoIterator for Frames {
+    type Item = Frame;
+    type IntoIter = alloc::vec::IntoIter<Frame>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.frames.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Frames {
+    type Item = &'a Frame;
+    type IntoIter = slice::Iter<'a, Frame>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.frames.iter()
+    }
+}
+
+/// Maps addresses to functions and source lines, using the DWARF line
+/// programs and inlining info where present, and otherwise the nearest
+/// `.symtab` or `.dynsym` symbol.
+pub struct Symbolizer<'a> {
+    context: addr2line::Context<Reader<'a>>,
+    /// Function and object symbols, sorted by address.
+    symbols: Vec<Symbol<'a>>,
+    load_bias: u64,
+}
+
+impl<'a> Symbolizer<'a> {
+    pub fn new(elf_file: &'a dyn ElfFile) -> Result<Self, Error> {
+        let endian = gimli_endian(elf_file.elf_header().endianness());
+        let sections = load_sections(elf_file, false, |data| match data {
+            Cow::Borrowed(data) => SectionBytes::Borrowed(data),
+            Cow::Owned(data) => SectionBytes::Owned(data.into()),
+        })?;
+        let dwarf = sections.borrow(|data| gimli::EndianReader::new(data.clone(), endian));
+        let context = addr2line::Context::from_dwarf(dwarf).map_err(Error::Dwarf)?;
+
+        let mut symbols: Vec<_> = elf_file
+            .symbol_iter()
+            .or_else(|| elf_file.dynamic_symbol_iter())
+            .into_iter()
+            .flatten()
+            .filter(|s| {
+                matches!(
+                    s.sym_type(),
+                    SymbolType::STT_FUNC
+                        | SymbolType::STT_OBJECT
+                        | SymbolType::OsSpecific(STT_GNU_IFUNC)
+                ) && s.section_index() != SectionIndex::SHN_UNDEF
+            })
+            .filter_map(|s| {
+                Some(Symbol {
+                    address: s.value(),
+                    size: s.size(),
+                    name: s.symbol_name().filter(|n| !n.is_empty())?,
+                })
+            })
+            .collect();
+        symbols.sort_by_key(|s| (s.address, s.size));
+
+        Ok(Self {
+            context,
+            symbols,
+            load_bias: 0,
+        })
+    }
+
+    /// Sets the difference between runtime and link-time addresses, such as
+    /// the base address a PIE or shared object was loaded at.
+    pub fn with_load_bias(mut self, load_bias: u64) -> Self {
+        self.load_bias = load_bias;
+        self
+    }
+
+    pub fn load_bias(&self) -> u64 {
+        self.load_bias
+    }
+
+    /// Symbolizes the runtime `address`. Falls back to the symbol containing
+    /// the address, or the nearest one before it if its size is unknown,
+    /// where the DWARF does not cover the address or is malformed.
+    pub fn symbolize(&self, address: u64) -> Frames {
+        let probe = address.wrapping_sub(self.load_bias);
+        let mut frames = self.dwarf_frames(probe).unwrap_or_default();
+        let symbol_name = || {
+            self.lookup_symbol(probe)
+                .map(|s| String::from_utf8_lossy(s.name).into_owned())
+        };
+        match frames.last_mut() {
+            Some(frame) => {
+                if frame.function.is_none() {
+                    frame.function = symbol_name();
+                }
+            }
+            None => {
+                if let Some(function) = symbol_name() {
+                    frames.push(Frame {
+                        function: Some(function),
+                        file: None,
+                        line: None,
+                        column: None,
+                    });
+                }
+            }
+        }
+        Frames { frames }
+    }
+
+    fn dwarf_frames(&self, probe: u64) -> Result<Vec<Frame>, gimli::Error> {
+        let mut iter = self.context.find_frames(probe).skip_all_loads()?;
+        let mut frames = Vec::new();
+        while let Some(frame) = iter.next()? {
+            let function = match &frame.function {
+                Some(function) => Some(function.raw_name()?.into_owned()),
+                None => None,
+            };
+            let location = frame.location.as_ref();
+            frames.push(Frame {
+                function,
+                file: location.and_then(|l| l.file).map(String::from),
+                line: location.and_then(|l| l.line),
+                column: location.and_then(|l| l.column),
+            });
+        }
+        Ok(frames)
+    }
+
+    /// The nearest symbol at or before `probe` whose size covers it, else
+    /// the nearest one if its size is unknown.
+    fn lookup_symbol(&self, probe: u64) -> Option<&Symbol<'a>> {
+        let end = self.symbols.partition_point(|s| s.address <= probe);
+        let candidates = &self.symbols[..end];
+        candidates
+            .iter()
+            .rev()
+            .find(|s| probe - s.address < s.size)
+            .or_else(|| candidates.last().filter(|s| s.size == 0))
+    }
+}
+
+impl<'a> fmt::Debug for Symbolizer<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Symbolizer")
+            .field("symbols", &self.symbols.len())
+            .field("load_bias", &self.load_bias)
+            .finish()
+    }
+}
@@ -0,0 +1,334 @@
+use super::ElfFile;
+use crate::elf_header::EV_CURRENT;
+use crate::program_header::ProgramType;
+use crate::section_header::{SectionType, SHN_XINDEX};
+use crate::Error;
+use core::fmt;
+use core::ops::Range;
+
+/// Number of findings a `ValidationReport` keeps. Further findings are only
+/// counted.
+pub const MAX_FINDINGS: usize = 32;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Unusual, but harmless.
+    Info,
+    /// Tolerated by common loaders, but not spec conforming.
+    Warning,
+    /// The file is malformed.
+    Error,
+}
+
+/// Which findings make a `ValidationReport` unacceptable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ValidationPolicy {
+    /// Never fail, only report.
+    Lenient,
+    /// Fail on `Severity::Error` findings.
+    #[default]
+    Standard,
+    /// Fail on `Severity::Warning` and `Severity::Error` findings.
+    Strict,
+}
+
+impl ValidationPolicy {
+    fn rejects(self, severity: Severity) -> bool {
+        match self {
+            ValidationPolicy::Lenient => false,
+            ValidationPolicy::Standard => severity >= Severity::Error,
+            ValidationPolicy::Strict => severity >= Severity::Warning,
+        }
+    }
+}
+
+/// A validation finding. Segment and section findings carry the index of the
+/// offending header.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FindingKind {
+    /// `EI_VERSION` is not `EV_CURRENT`.
+    UnsupportedHeaderVersion(u8),
+    /// `e_version` is not `EV_CURRENT`.
+    UnsupportedVersion(u32),
+    /// `e_ehsize` differs from the ELF header structure.
+    InvalidHeaderSize(u16),
+    ProgramHeaderTableOutOfBounds,
+    SectionHeaderTableOutOfBounds,
+    /// The ELF header, program header table and section header table
+    /// overlap.
+    HeaderTablesOverlap,
+    SegmentOutOfBounds(usize),
+    SegmentAlignmentNotPowerOfTwo(usize),
+    /// `p_offset` and `p_vaddr` are not congruent modulo `p_align`.
+    SegmentMisaligned(usize),
+    SectionOutOfBounds(usize),
+    /// The section contents overlap the ELF header or a header table.
+    SectionOverlapsHeaders(usize),
+    /// `e_shstrndx` is out of range or not a `SHT_STRTAB` section.
+    InvalidShstrIndex(usize),
+    /// The last byte of a `SHT_STRTAB` section is not NUL.
+    StringTableNotTerminated(usize),
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            FindingKind::UnsupportedHeaderVersion(v) => {
+                write!(f, "unsupported EI_VERSION {}", v)
+            }
+            FindingKind::UnsupportedVersion(v) => write!(f, "unsupported e_version {}", v),
+            FindingKind::InvalidHeaderSize(n) => write!(f, "unexpected e_ehsize {}", n),
+            FindingKind::ProgramHeaderTableOutOfBounds => {
+                write!(f, "program header table is out of bounds")
+            }
+            FindingKind::SectionHeaderTableOutOfBounds => {
+                write!(f, "section header table is out of bounds")
+            }
+            FindingKind::HeaderTablesOverlap => write!(f, "header tables overlap"),
+            FindingKind::SegmentOutOfBounds(i) => write!(f, "segment {} is out of bounds", i),
+            FindingKind::SegmentAlignmentNotPowerOfTwo(i) => {
+                write!(f, "segment {} alignment is not a power of two", i)
+            }
+            FindingKind::SegmentMisaligned(i) => {
+                write!(f, "segment {} offset and address are not congruent", i)
+            }
+            FindingKind::SectionOutOfBounds(i) => write!(f, "section {} is out of bounds", i),
+            FindingKind::SectionOverlapsHeaders(i) => {
+                write!(f, "section {} overlaps the ELF headers", i)
+            }
+            FindingKind::InvalidShstrIndex(i) => write!(f, "invalid e_shstrndx {}", i),
+            FindingKind::StringTableNotTerminated(i) => {
+                write!(f, "string table {} is not NUL terminated", i)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub kind: FindingKind,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.severity, self.kind)
+    }
+}
+
+/// Findings of `validate`, in the order they were found.
+#[derive(Debug, Clone)]
+pub struct ValidationReport {
+    findings: [Option<Finding>; MAX_FINDINGS],
+    len: usize,
+    dropped: usize,
+    /// First finding of `Severity::Warning` or higher, even if dropped.
+    first_warning: Option<Finding>,
+    /// First finding of `Severity::Error`, even if dropped.
+    first_error: Option<Finding>,
+}
+
+impl ValidationReport {
+    fn new() -> Self {
+        Self {
+            findings: [None; MAX_FINDINGS],
+            len: 0,
+            dropped: 0,
+            first_warning: None,
+            first_error: None,
+        }
+    }
+
+    fn add(&mut self, severity: Severity, kind: FindingKind) {
+        let finding = Finding { severity, kind };
+        if severity >= Severity::Warning && self.first_warning.is_none() {
+            self.first_warning = Some(finding);
+        }
+        if severity >= Severity::Error && self.first_error.is_none() {
+            self.first_error = Some(finding);
+        }
+        match self.findings.get_mut(self.len) {
+            Some(slot) => {
+                *slot = Some(finding);
+                self.len += 1;
+            }
+            None => self.dropped += 1,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Finding> {
+        self.findings[..self.len].iter().flatten()
+    }
+
+    /// Number of findings that did not fit in the report.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Highest severity of the kept findings.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.iter().map(|f| f.severity).max()
+    }
+
+    /// The first finding `policy` rejects, including dropped findings.
+    pub fn rejected(&self, policy: ValidationPolicy) -> Option<Finding> {
+        [self.first_warning, self.first_error]
+            .iter()
+            .flatten()
+            .find(|f| policy.rejects(f.severity))
+            .copied()
+    }
+
+    pub fn is_acceptable(&self, policy: ValidationPolicy) -> bool {
+        self.rejected(policy).is_none()
+    }
+
+    /// Fails with `Error::ValidationFailed` on the first finding `policy`
+    /// rejects.
+    pub fn check(&self, policy: ValidationPolicy) -> Result<(), Error> {
+        match self.rejected(policy) {
+            Some(finding) => Err(Error::ValidationFailed(finding)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Header table layout, resolved by the caller for the file's class and byte
+/// order.
+pub(crate) struct Layout {
+    pub ehsize: usize,
+    pub ph_num: usize,
+    pub sh_num: usize,
+}
+
+/// `start..start + size`, or `None` if it overflows.
+fn span(start: u64, size: u64) -> Option<Range<u64>> {
+    Some(start..start.checked_add(size)?)
+}
+
+fn overlaps(a: &Range<u64>, b: &Range<u64>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+pub(crate) fn validate(elf: &dyn ElfFile, layout: Layout) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    let file_size = elf.content().len() as u64;
+    let in_file = |r: &Option<Range<u64>>| r.as_ref().is_some_and(|r| r.end <= file_size);
+    let elf_header = elf.elf_header();
+
+    if elf_header.header_version() != EV_CURRENT {
+        report.add(
+            Severity::Error,
+            FindingKind::UnsupportedHeaderVersion(elf_header.header_version()),
+        );
+    }
+    if elf_header.elf_version() != EV_CURRENT as u32 {
+        report.add(
+            Severity::Error,
+            FindingKind::UnsupportedVersion(elf_header.elf_version()),
+        );
+    }
+    let ehsize = elf_header.elf_header_size();
+    if (ehsize as usize) < layout.ehsize {
+        report.add(Severity::Error, FindingKind::InvalidHeaderSize(ehsize));
+    } else if ehsize as usize > layout.ehsize {
+        report.add(Severity::Warning, FindingKind::InvalidHeaderSize(ehsize));
+    }
+
+    let header = 0..layout.ehsize as u64;
+    let ph_table = span(
+        elf_header.program_header_offset(),
+        (layout.ph_num as u64).saturating_mul(elf_header.program_header_entry_size() as u64),
+    );
+    let sh_table = span(
+        elf_header.section_header_offset(),
+        (layout.sh_num as u64).saturating_mul(elf_header.section_header_entry_size() as u64),
+    );
+    if layout.ph_num != 0 && !in_file(&ph_table) {
+        report.add(Severity::Error, FindingKind::ProgramHeaderTableOutOfBounds);
+    }
+    if layout.sh_num != 0 && !in_file(&sh_table) {
+        report.add(Severity::Error, FindingKind::SectionHeaderTableOutOfBounds);
+    }
+    let ph_table = ph_table.filter(|_| layout.ph_num != 0);
+    let sh_table = sh_table.filter(|_| layout.sh_num != 0);
+    let tables = [Some(header), ph_table, sh_table];
+    let overlapping = |r: &Range<u64>| tables.iter().flatten().any(|t| overlaps(r, t));
+    let tables_overlap = tables.iter().enumerate().any(|(i, a)| {
+        tables[i + 1..]
+            .iter()
+            .any(|b| matches!((a, b), (Some(a), Some(b)) if overlaps(a, b)))
+    });
+    if tables_overlap {
+        report.add(Severity::Error, FindingKind::HeaderTablesOverlap);
+    }
+
+    for index in 0..layout.ph_num {
+        let ph = match elf.program_header_nth(index) {
+            Some(ph) => ph,
+            None => break,
+        };
+        if !in_file(&span(ph.offset(), ph.filesz())) {
+            report.add(Severity::Error, FindingKind::SegmentOutOfBounds(index));
+        }
+        let align = ph.align();
+        if align > 1 && !align.is_power_of_two() {
+            report.add(
+                Severity::Error,
+                FindingKind::SegmentAlignmentNotPowerOfTwo(index),
+            );
+        } else if align > 1 && ph.offset() % align != ph.vaddr() % align {
+            let severity = match ph.ph_type() {
+                ProgramType::LOAD => Severity::Error,
+                _ => Severity::Warning,
+            };
+            report.add(severity, FindingKind::SegmentMisaligned(index));
+        }
+    }
+
+    for index in 0..layout.sh_num {
+        let sh = match elf.section_header_nth(index) {
+            Some(sh) => sh,
+            None => break,
+        };
+        match sh.sh_type() {
+            SectionType::SHT_NULL | SectionType::SHT_NOBITS => continue,
+            _ => {}
+        }
+        let range = span(sh.offset(), sh.size());
+        if !in_file(&range) {
+            report.add(Severity::Error, FindingKind::SectionOutOfBounds(index));
+            continue;
+        }
+        if sh.size() != 0 && range.as_ref().is_some_and(overlapping) {
+            report.add(Severity::Error, FindingKind::SectionOverlapsHeaders(index));
+        }
+        if sh.sh_type() == SectionType::SHT_STRTAB
+            && sh.content().and_then(|c| c.last()).is_some_and(|&b| b != 0)
+        {
+            report.add(
+                Severity::Warning,
+                FindingKind::StringTableNotTerminated(index),
+            );
+        }
+    }
+
+    let shstr_index = match elf_header.shstr_index() {
+        SHN_XINDEX => elf.section_header_nth(0).map_or(0, |s| s.link() as usize),
+        n => n as usize,
+    };
+    if layout.sh_num != 0 && shstr_index != 0 {
+        let is_strtab = elf
+            .section_header_nth(shstr_index)
+            .is_some_and(|s| s.sh_type() == SectionType::SHT_STRTAB);
+        if !is_strtab {
+            report.add(Severity::Error, FindingKind::InvalidShstrIndex(shstr_index));
+        }
+    }
+
+    report
+}
@@ -0,0 +1,410 @@
+use super::{segment_tail, ElfFile, StringTable};
+use crate::dynamic::DynamicTag;
+use crate::elf_header::ElfEndian;
+use crate::endian::{read_u16, read_u32};
+use crate::section_header::SectionType;
+use crate::version::{compare_versions, is_numbered_version, VersionFlags, VersionIndex};
+use core::fmt;
+
+/// A version table, with the entry count and string table given by the
+/// dynamic section or its section header.
+struct VersionTable<'a> {
+    data: &'a [u8],
+    num: Option<usize>,
+    strtab: Option<StringTable<'a>>,
+}
+
+/// Finds the version table in the section of type `sh_type`, which gives its
+/// exact size, falling back to dynamic tag `tag` through `PT_DYNAMIC`.
+fn version_table<'a>(
+    elf_file: &'a dyn ElfFile,
+    tag: DynamicTag,
+    num_tag: Option<DynamicTag>,
+    sh_type: SectionType,
+) -> Option<VersionTable<'a>> {
+    let from_dynamic = || {
+        let dynamic = elf_file.dynamic_iter()?;
+        let strtab = dynamic.string_table();
+        let mut addr = None;
+        let mut num = None;
+        for entry in dynamic {
+            if entry.tag() == tag {
+                addr = Some(entry.value());
+            } else if Some(entry.tag()) == num_tag {
+                num = Some(entry.value() as usize);
+            }
+        }
+        Some(VersionTable {
+            data: segment_tail(elf_file, addr?)?,
+            num,
+            strtab,
+        })
+    };
+    let from_sections = || {
+        let section = elf_file
+            .section_header_iter()
+            .find(|s| s.sh_type() == sh_type)?;
+        Some(VersionTable {
+            data: section.content()?,
+            num: num_tag.map(|_| section.info() as usize),
+            strtab: section.linked_string_table(),
+        })
+    };
+    from_sections().or_else(from_dynamic)
+}
+
+/// The `.gnu.version` table, holding the version index of each dynamic
+/// symbol.
+#[derive(Copy, Clone)]
+pub struct VersymTable<'a> {
+    endian: ElfEndian,
+    data: &'a [u8],
+}
+
+impl<'a> VersymTable<'a> {
+    pub(crate) fn find(elf_file: &'a dyn ElfFile) -> Option<Self> {
+        let table = version_table(
+            elf_file,
+            DynamicTag::DT_VERSYM,
+            None,
+            SectionType::SHT_GNU_versym,
+        )?;
+        Some(Self {
+            endian: elf_file.elf_header().endianness(),
+            data: table.data,
+        })
+    }
+
+    /// Version index of the `index`-th dynamic symbol.
+    pub fn get(&self, index: usize) -> Option<VersionIndex> {
+        read_u16(self.endian, self.data, index.checked_mul(2)?).map(VersionIndex)
+    }
+
+    /// Iterates the entries. Found through `PT_DYNAMIC` alone, the table
+    /// extends past the last dynamic symbol to the end of its segment.
+    pub fn iter(&self) -> impl Iterator<Item = VersionIndex> + 'a {
+        let table = *self;
+        (0..self.data.len() / 2).filter_map(move |i| table.get(i))
+    }
+}
+
+impl<'a> fmt::Debug for VersymTable<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Version Symbol Table")
+            .field("entries", &(self.data.len() / 2))
+            .finish()
+    }
+}
+
+/// Walks a chain of version structures linked by their `next` offsets.
+#[derive(Copy, Clone)]
+struct Chain<'a> {
+    endian: ElfEndian,
+    data: &'a [u8],
+    offset: Option<usize>,
+    remaining: Option<usize>,
+}
+
+impl<'a> Chain<'a> {
+    /// Returns the offset of the next structure, whose `next` field is at
+    /// `next_field` within it.
+    fn advance(&mut self, next_field: usize) -> Option<usize> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let offset = self.offset?;
+        let next = read_u32(self.endian, self.data, offset.checked_add(next_field)?)? as usize;
+        self.offset = match next {
+            0 => None,
+            n => offset.checked_add(n),
+        };
+        self.remaining = self.remaining.map(|n| n - 1);
+        Some(offset)
+    }
+}
+
+/// A `Verdef` entry of the `.gnu.version_d` table.
+pub struct VerdefEntry<'a> {
+    endian: ElfEndian,
+    data: &'a [u8],
+    offset: usize,
+    strtab: Option<StringTable<'a>>,
+}
+
+impl<'a> VerdefEntry<'a> {
+    fn u16_at(&self, field: usize) -> u16 {
+        read_u16(self.endian, self.data, self.offset + field).unwrap_or(0)
+    }
+
+    pub fn version(&self) -> u16 {
+        self.u16_at(0)
+    }
+
+    pub fn flags(&self) -> VersionFlags {
+        VersionFlags::from_bits_retain(self.u16_at(2))
+    }
+
+    /// The version index symbols refer to this definition by.
+    pub fn index(&self) -> u16 {
+        self.u16_at(4)
+    }
+
+    pub fn hash(&self) -> u32 {
+        read_u32(self.endian, self.data, self.offset + 8).unwrap_or(0)
+    }
+
+    /// Name of the version, followed by the names of the versions it
+    /// inherits from.
+    pub fn name_iter(&self) -> VerdauxIter<'a> {
+        let aux = read_u32(self.endian, self.data, self.offset + 12)
+            .and_then(|n| self.offset.checked_add(n as usize));
+        VerdauxIter {
+            chain: Chain {
+                endian: self.endian,
+                data: self.data,
+                offset: aux,
+                remaining: Some(self.u16_at(6) as usize),
+            },
+            strtab: self.strtab,
+        }
+    }
+
+    pub fn name(&self) -> Option<&'a [u8]> {
+        self.name_iter().next()
+    }
+}
+
+impl<'a> fmt::Debug for VerdefEntry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self
+            .name()
+            .and_then(|n| core::str::from_utf8(n).ok())
+            .unwrap_or("");
+        f.debug_struct("Version Definition")
+            .field("name", &name)
+            .field("flags", &self.flags())
+            .field("index", &self.index())
+            .finish()
+    }
+}
+
+/// Iterates the names of a version definition.
+pub struct VerdauxIter<'a> {
+    chain: Chain<'a>,
+    strtab: Option<StringTable<'a>>,
+}
+
+impl<'a> Iterator for VerdauxIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.chain.advance(4)?;
+        let name = read_u32(self.chain.endian, self.chain.data, offset)?;
+        self.strtab?.get(name as usize).ok()
+    }
+}
+
+/// Iterates the `.gnu.version_d` table.
+pub struct VerdefIter<'a> {
+    chain: Chain<'a>,
+    strtab: Option<StringTable<'a>>,
+}
+
+impl<'a> VerdefIter<'a> {
+    pub(crate) fn find(elf_file: &'a dyn ElfFile) -> Option<Self> {
+        let table = version_table(
+            elf_file,
+            DynamicTag::DT_VERDEF,
+            Some(DynamicTag::DT_VERDEFNUM),
+            SectionType::SHT_GNU_verdef,
+        )?;
+        Some(Self {
+            chain: Chain {
+                endian: elf_file.elf_header().endianness(),
+                data: table.data,
+                offset: Some(0),
+                remaining: table.num,
+            },
+            strtab: table.strtab,
+        })
+    }
+}
+
+impl<'a> Iterator for VerdefIter<'a> {
+    type Item = VerdefEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.chain.advance(16)?;
+        // Stop at a truncated entry rather than reading zeros.
+        self.chain.data.get(offset..offset.checked_add(20)?)?;
+        Some(VerdefEntry {
+            endian: self.chain.endian,
+            data: self.chain.data,
+            offset,
+            strtab: self.strtab,
+        })
+    }
+}
+
+/// A `Vernaux` entry: one version required from a library.
+pub struct VernauxEntry<'a> {
+    hash: u32,
+    flags: VersionFlags,
+    index: u16,
+    name: Option<&'a [u8]>,
+}
+
+impl<'a> VernauxEntry<'a> {
+    pub fn hash(&self) -> u32 {
+        self.hash
+    }
+
+    pub fn flags(&self) -> VersionFlags {
+        self.flags
+    }
+
+    /// The version index symbols refer to this requirement by.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    pub fn name(&self) -> Option<&'a [u8]> {
+        self.name
+    }
+}
+
+impl<'a> fmt::Debug for VernauxEntry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self
+            .name()
+            .and_then(|n| core::str::from_utf8(n).ok())
+            .unwrap_or("");
+        f.debug_struct("Version Requirement")
+            .field("name", &name)
+            .field("flags", &self.flags())
+            .field("index", &self.index())
+            .finish()
+    }
+}
+
+/// Iterates the versions required from a library.
+pub struct VernauxIter<'a> {
+    chain: Chain<'a>,
+    strtab: Option<StringTable<'a>>,
+}
+
+impl<'a> Iterator for VernauxIter<'a> {
+    type Item = VernauxEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.chain.advance(12)?;
+        let (endian, data) = (self.chain.endian, self.chain.data);
+        let name = read_u32(endian, data, offset + 8)?;
+        Some(VernauxEntry {
+            hash: read_u32(endian, data, offset)?,
+            flags: VersionFlags::from_bits_retain(read_u16(endian, data, offset + 4)?),
+            index: read_u16(endian, data, offset + 6)?,
+            name: self.strtab.and_then(|s| s.get(name as usize).ok()),
+        })
+    }
+}
+
+/// A `Verneed` entry of the `.gnu.version_r` table: the versions required
+/// from one library.
+pub struct VerneedEntry<'a> {
+    endian: ElfEndian,
+    data: &'a [u8],
+    offset: usize,
+    strtab: Option<StringTable<'a>>,
+}
+
+impl<'a> VerneedEntry<'a> {
+    pub fn version(&self) -> u16 {
+        read_u16(self.endian, self.data, self.offset).unwrap_or(0)
+    }
+
+    /// Name of the library, as in its `DT_NEEDED` entry.
+    pub fn file(&self) -> Option<&'a [u8]> {
+        let file = read_u32(self.endian, self.data, self.offset + 4)?;
+        self.strtab?.get(file as usize).ok()
+    }
+
+    pub fn aux_iter(&self) -> VernauxIter<'a> {
+        let aux = read_u32(self.endian, self.data, self.offset + 8)
+            .and_then(|n| self.offset.checked_add(n as usize));
+        let num = read_u16(self.endian, self.data, self.offset + 2).unwrap_or(0);
+        VernauxIter {
+            chain: Chain {
+                endian: self.endian,
+                data: self.data,
+                offset: aux,
+                remaining: Some(num as usize),
+            },
+            strtab: self.strtab,
+        }
+    }
+
+    /// The highest numbered version required from the library, such as
+    /// `GLIBC_2.17`, ordered by `compare_versions`. Versions without a
+    /// number, such as `GLIBC_PRIVATE`, are ignored.
+    pub fn max_version(&self) -> Option<&'a [u8]> {
+        self.aux_iter()
+            .filter_map(|a| a.name())
+            .filter(|n| is_numbered_version(n))
+            .max_by(|a, b| compare_versions(a, b))
+    }
+}
+
+impl<'a> fmt::Debug for VerneedEntry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self
+            .file()
+            .and_then(|n| core::str::from_utf8(n).ok())
+            .unwrap_or("");
+        f.debug_struct("Version Needed")
+            .field("file", &file)
+            .field("count", &self.aux_iter().count())
+            .finish()
+    }
+}
+
+/// Iterates the `.gnu.version_r` table.
+pub struct VerneedIter<'a> {
+    chain: Chain<'a>,
+    strtab: Option<StringTable<'a>>,
+}
+
+impl<'a> VerneedIter<'a> {
+    pub(crate) fn find(elf_file: &'a dyn ElfFile) -> Option<Self> {
+        let table = version_table(
+            elf_file,
+            DynamicTag::DT_VERNEED,
+            Some(DynamicTag::DT_VERNEEDNUM),
+            SectionType::SHT_GNU_verneed,
+        )?;
+        Some(Self {
+            chain: Chain {
+                endian: elf_file.elf_header().endianness(),
+                data: table.data,
+                offset: Some(0),
+                remaining: table.num,
+            },
+            strtab: table.strtab,
+        })
+    }
+}
+
+impl<'a> Iterator for VerneedIter<'a> {
+    type Item = VerneedEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.chain.advance(12)?;
+        self.chain.data.get(offset..offset.checked_add(16)?)?;
+        Some(VerneedEntry {
+            endian: self.chain.endian,
+            data: self.chain.data,
+            offset,
+            strtab: self.strtab,
+        })
+    }
+}
@@ -1,11 +1,9 @@
-use core::ptr::read_unaligned;
-use num_traits::PrimInt;
-
 use super::{ElfAbi, ElfClass, ElfEndian, ElfHeaderRaw, ElfMachine, ElfType};
+use crate::endian::{Endianness, Pod, Word, U16, U32, U64};
 
 #[repr(C)]
 #[derive(Debug)]
-pub struct ElfHeaderGen<T: PrimInt> {
+pub struct ElfHeaderGen<W: Word, E: Endianness> {
     magic: [u8; 4],
     class: u8,
     endianness: u8,
@@ -13,22 +11,24 @@ pub struct ElfHeaderGen<T: PrimInt> {
     abi: u8,
     abi_version: u8,
     unused: [u8; 7],
-    elftype: u16,
-    machine: u16,
-    elf_version: u32,
-    entry: T,
-    phoff: T,
-    shoff: T,
-    flags: u32,
-    ehsize: u16,
-    phentsize: u16,
-    phnum: u16,
-    shentsize: u16,
-    shnum: u16,
-    shstrndx: u16,
+    elftype: U16<E>,
+    machine: U16<E>,
+    elf_version: U32<E>,
+    entry: W,
+    phoff: W,
+    shoff: W,
+    flags: U32<E>,
+    ehsize: U16<E>,
+    phentsize: U16<E>,
+    phnum: U16<E>,
+    shentsize: U16<E>,
+    shnum: U16<E>,
+    shstrndx: U16<E>,
 }
 
-impl<T: PrimInt + Into<u64>> ElfHeaderRaw for ElfHeaderGen<T> {
+unsafe impl<W: Word, E: Endianness> Pod for ElfHeaderGen<W, E> {}
+
+impl<W: Word, E: Endianness> ElfHeaderRaw for ElfHeaderGen<W, E> {
     fn class(&self) -> ElfClass {
         self.class.into()
     }
@@ -50,57 +50,57 @@ impl<T: PrimInt + Into<u64>> ElfHeaderRaw for ElfHeaderGen<T> {
     }
 
     fn elftype(&self) -> ElfType {
-        unsafe { read_unaligned(&self.elftype).into() }
+        self.elftype.get().into()
     }
 
     fn machine(&self) -> ElfMachine {
-        unsafe { read_unaligned(&self.machine).into() }
+        self.machine.get().into()
     }
 
     fn elf_version(&self) -> u32 {
-        unsafe { read_unaligned(&self.elf_version) }
+        self.elf_version.get()
     }
 
     fn entry_point(&self) -> u64 {
-        unsafe { read_unaligned(&self.entry).into() }
+        self.entry.to_u64()
     }
 
     fn program_header_offset(&self) -> u64 {
-        unsafe { read_unaligned(&self.phoff).into() }
+        self.phoff.to_u64()
     }
 
     fn section_header_offset(&self) -> u64 {
-        unsafe { read_unaligned(&self.shoff).into() }
+        self.shoff.to_u64()
     }
 
     fn flags(&self) -> u32 {
-        unsafe { read_unaligned(&self.flags) }
+        self.flags.get()
     }
 
     fn elf_header_size(&self) -> u16 {
-        unsafe { read_unaligned(&self.ehsize) }
+        self.ehsize.get()
     }
 
     fn program_header_entry_size(&self) -> u16 {
-        unsafe { read_unaligned(&self.phentsize) }
+        self.phentsize.get()
     }
 
     fn program_header_entry_num(&self) -> u16 {
-        unsafe { read_unaligned(&self.phnum) }
+        self.phnum.get()
     }
 
     fn section_header_entry_size(&self) -> u16 {
-        unsafe { read_unaligned(&self.shentsize) }
+        self.shentsize.get()
     }
 
     fn section_header_entry_num(&self) -> u16 {
-        unsafe { read_unaligned(&self.shnum) }
+        self.shnum.get()
     }
 
     fn shstr_index(&self) -> u16 {
-        unsafe { read_unaligned(&self.shstrndx) }
+        self.shstrndx.get()
     }
 }
 
-pub type ElfHeader32 = ElfHeaderGen<u32>;
-pub type ElfHeader64 = ElfHeaderGen<u64>;
+pub type ElfHeader32<E> = ElfHeaderGen<U32<E>, E>;
+pub type ElfHeader64<E> = ElfHeaderGen<U64<E>, E>;
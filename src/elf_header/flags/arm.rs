@@ -0,0 +1,73 @@
+use core::fmt;
+
+const EF_ARM_EABIMASK: u32 = 0xff00_0000;
+const EF_ARM_BE8: u32 = 0x0080_0000;
+const EF_ARM_LE8: u32 = 0x0040_0000;
+const EF_ARM_ABI_FLOAT_SOFT: u32 = 0x200;
+const EF_ARM_ABI_FLOAT_HARD: u32 = 0x400;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArmFloatAbi {
+    Soft,
+    Hard,
+}
+
+/// ARM `e_flags`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ArmFlags(pub u32);
+
+impl ArmFlags {
+    /// The ARM EABI version, or `None` for pre-EABI (GNU) objects.
+    pub fn eabi_version(&self) -> Option<u8> {
+        match (self.0 & EF_ARM_EABIMASK) >> 24 {
+            0 => None,
+            n => Some(n as u8),
+        }
+    }
+
+    /// The floating point calling convention. Only recorded from EABI
+    /// version 5 on.
+    pub fn float_abi(&self) -> Option<ArmFloatAbi> {
+        if self.eabi_version() != Some(5) {
+            return None;
+        }
+        if self.0 & EF_ARM_ABI_FLOAT_HARD != 0 {
+            Some(ArmFloatAbi::Hard)
+        } else if self.0 & EF_ARM_ABI_FLOAT_SOFT != 0 {
+            Some(ArmFloatAbi::Soft)
+        } else {
+            None
+        }
+    }
+
+    /// Code is byte invariant big endian (BE-8).
+    pub fn is_be8(&self) -> bool {
+        self.0 & EF_ARM_BE8 != 0
+    }
+
+    pub fn is_le8(&self) -> bool {
+        self.0 & EF_ARM_LE8 != 0
+    }
+}
+
+impl fmt::Display for ArmFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)?;
+        match self.eabi_version() {
+            None => return Ok(()),
+            Some(n @ 1..=5) => write!(f, ", Version{} EABI", n)?,
+            Some(_) => f.write_str(", <unrecognized EABI>")?,
+        }
+        if self.is_be8() {
+            f.write_str(", BE8")?;
+        }
+        if self.is_le8() {
+            f.write_str(", LE8")?;
+        }
+        match self.float_abi() {
+            Some(ArmFloatAbi::Soft) => f.write_str(", soft-float ABI"),
+            Some(ArmFloatAbi::Hard) => f.write_str(", hard-float ABI"),
+            None => Ok(()),
+        }
+    }
+}
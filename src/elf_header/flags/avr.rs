@@ -0,0 +1,35 @@
+use core::fmt;
+
+const EF_AVR_MACH: u32 = 0x7f;
+const EF_AVR_LINKRELAX_PREPARED: u32 = 0x80;
+
+/// AVR `e_flags`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AvrFlags(pub u32);
+
+impl AvrFlags {
+    /// The AVR architecture, e.g. 5 for avr5, 100 for avrtiny and 101 to 107
+    /// for avrxmega1 to avrxmega7.
+    pub fn arch(&self) -> u8 {
+        (self.0 & EF_AVR_MACH) as u8
+    }
+
+    /// The object was prepared for linker relaxation.
+    pub fn link_relax(&self) -> bool {
+        self.0 & EF_AVR_LINKRELAX_PREPARED != 0
+    }
+}
+
+impl fmt::Display for AvrFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)?;
+        if self.0 == 0 {
+            return Ok(());
+        }
+        write!(f, ", avr:{}", self.arch())?;
+        if self.link_relax() {
+            f.write_str(", link-relax")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use core::fmt;
+
+const EF_LOONGARCH_ABI_MODIFIER_MASK: u32 = 0x7;
+const EF_LOONGARCH_OBJABI_MASK: u32 = 0xc0;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LoongArchFloatAbi {
+    Soft,
+    Single,
+    Double,
+}
+
+/// LoongArch `e_flags`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LoongArchFlags(pub u32);
+
+impl LoongArchFlags {
+    /// The floating point ABI modifier, or `None` if it is invalid.
+    pub fn float_abi(&self) -> Option<LoongArchFloatAbi> {
+        match self.0 & EF_LOONGARCH_ABI_MODIFIER_MASK {
+            1 => Some(LoongArchFloatAbi::Soft),
+            2 => Some(LoongArchFloatAbi::Single),
+            3 => Some(LoongArchFloatAbi::Double),
+            _ => None,
+        }
+    }
+
+    /// The object file ABI version.
+    pub fn obj_abi_version(&self) -> u8 {
+        ((self.0 & EF_LOONGARCH_OBJABI_MASK) >> 6) as u8
+    }
+}
+
+impl fmt::Display for LoongArchFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)?;
+        if self.0 == 0 {
+            return Ok(());
+        }
+        match self.float_abi() {
+            Some(LoongArchFloatAbi::Soft) => f.write_str(", SOFT-FLOAT")?,
+            Some(LoongArchFloatAbi::Single) => f.write_str(", SINGLE-FLOAT")?,
+            Some(LoongArchFloatAbi::Double) => f.write_str(", DOUBLE-FLOAT")?,
+            None => {}
+        }
+        write!(f, ", OBJ-v{}", self.obj_abi_version())
+    }
+}
@@ -0,0 +1,143 @@
+use core::fmt;
+
+const EF_MIPS_NOREORDER: u32 = 0x1;
+const EF_MIPS_PIC: u32 = 0x2;
+const EF_MIPS_CPIC: u32 = 0x4;
+const EF_MIPS_ABI2: u32 = 0x20;
+const EF_MIPS_FP64: u32 = 0x200;
+const EF_MIPS_NAN2008: u32 = 0x400;
+const EF_MIPS_ABI: u32 = 0x0000_f000;
+const EF_MIPS_ARCH: u32 = 0xf000_0000;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MipsArch {
+    Mips1,
+    Mips2,
+    Mips3,
+    Mips4,
+    Mips5,
+    Mips32,
+    Mips64,
+    Mips32R2,
+    Mips64R2,
+    Mips32R6,
+    Mips64R6,
+    Unknown(u32),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MipsAbi {
+    O32,
+    O64,
+    EABI32,
+    EABI64,
+    /// Signalled by `EF_MIPS_ABI2` rather than the ABI field.
+    N32,
+}
+
+/// MIPS `e_flags`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MipsFlags(pub u32);
+
+impl MipsFlags {
+    pub fn arch(&self) -> MipsArch {
+        match (self.0 & EF_MIPS_ARCH) >> 28 {
+            0x0 => MipsArch::Mips1,
+            0x1 => MipsArch::Mips2,
+            0x2 => MipsArch::Mips3,
+            0x3 => MipsArch::Mips4,
+            0x4 => MipsArch::Mips5,
+            0x5 => MipsArch::Mips32,
+            0x6 => MipsArch::Mips64,
+            0x7 => MipsArch::Mips32R2,
+            0x8 => MipsArch::Mips64R2,
+            0x9 => MipsArch::Mips32R6,
+            0xa => MipsArch::Mips64R6,
+            n => MipsArch::Unknown(n),
+        }
+    }
+
+    /// The calling convention, or `None` if unspecified. 64-bit objects
+    /// leave it unspecified for n64.
+    pub fn abi(&self) -> Option<MipsAbi> {
+        match self.0 & EF_MIPS_ABI {
+            0x1000 => Some(MipsAbi::O32),
+            0x2000 => Some(MipsAbi::O64),
+            0x3000 => Some(MipsAbi::EABI32),
+            0x4000 => Some(MipsAbi::EABI64),
+            _ if self.0 & EF_MIPS_ABI2 != 0 => Some(MipsAbi::N32),
+            _ => None,
+        }
+    }
+
+    pub fn noreorder(&self) -> bool {
+        self.0 & EF_MIPS_NOREORDER != 0
+    }
+
+    /// Contains position independent code.
+    pub fn pic(&self) -> bool {
+        self.0 & EF_MIPS_PIC != 0
+    }
+
+    /// Follows the standard calling sequence for PIC.
+    pub fn cpic(&self) -> bool {
+        self.0 & EF_MIPS_CPIC != 0
+    }
+
+    pub fn fp64(&self) -> bool {
+        self.0 & EF_MIPS_FP64 != 0
+    }
+
+    /// Uses the IEEE 754-2008 NaN encoding.
+    pub fn nan2008(&self) -> bool {
+        self.0 & EF_MIPS_NAN2008 != 0
+    }
+}
+
+impl fmt::Display for MipsFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)?;
+        if self.0 == 0 {
+            return Ok(());
+        }
+        if self.noreorder() {
+            f.write_str(", noreorder")?;
+        }
+        if self.pic() {
+            f.write_str(", pic")?;
+        }
+        if self.cpic() {
+            f.write_str(", cpic")?;
+        }
+        if self.0 & EF_MIPS_ABI2 != 0 {
+            f.write_str(", abi2")?;
+        }
+        if self.fp64() {
+            f.write_str(", fp64")?;
+        }
+        if self.nan2008() {
+            f.write_str(", nan2008")?;
+        }
+        match self.0 & EF_MIPS_ABI {
+            0x1000 => f.write_str(", o32")?,
+            0x2000 => f.write_str(", o64")?,
+            0x3000 => f.write_str(", eabi32")?,
+            0x4000 => f.write_str(", eabi64")?,
+            _ => {}
+        }
+        match self.arch() {
+            MipsArch::Mips1 => f.write_str(", mips1"),
+            MipsArch::Mips2 => f.write_str(", mips2"),
+            MipsArch::Mips3 => f.write_str(", mips3"),
+            MipsArch::Mips4 => f.write_str(", mips4"),
+            MipsArch::Mips5 => f.write_str(", mips5"),
+            MipsArch::Mips32 => f.write_str(", mips32"),
+            MipsArch::Mips64 => f.write_str(", mips64"),
+            MipsArch::Mips32R2 => f.write_str(", mips32r2"),
+            MipsArch::Mips64R2 => f.write_str(", mips64r2"),
+            MipsArch::Mips32R6 => f.write_str(", mips32r6"),
+            MipsArch::Mips64R6 => f.write_str(", mips64r6"),
+            MipsArch::Unknown(_) => f.write_str(", unknown ISA"),
+        }
+    }
+}
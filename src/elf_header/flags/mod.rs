@@ -0,0 +1,57 @@
+use super::ElfMachine;
+use core::fmt;
+
+mod arm;
+mod avr;
+mod loongarch;
+mod mips;
+mod ppc64;
+mod riscv;
+
+pub use arm::{ArmFlags, ArmFloatAbi};
+pub use avr::AvrFlags;
+pub use loongarch::{LoongArchFlags, LoongArchFloatAbi};
+pub use mips::{MipsAbi, MipsArch, MipsFlags};
+pub use ppc64::{Ppc64Abi, Ppc64Flags};
+pub use riscv::{RiscVFlags, RiscVFloatAbi};
+
+/// `e_flags` decoded according to the target machine of the file. `Display`
+/// follows the `Flags:` line of `readelf -h`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MachineFlags {
+    Arm(ArmFlags),
+    RiscV(RiscVFlags),
+    Mips(MipsFlags),
+    Ppc64(Ppc64Flags),
+    Avr(AvrFlags),
+    LoongArch(LoongArchFlags),
+    Unknown(u32),
+}
+
+impl MachineFlags {
+    pub fn new(machine: ElfMachine, flags: u32) -> Self {
+        match machine {
+            ElfMachine::ARM => MachineFlags::Arm(ArmFlags(flags)),
+            ElfMachine::RISC_V => MachineFlags::RiscV(RiscVFlags(flags)),
+            ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE => MachineFlags::Mips(MipsFlags(flags)),
+            ElfMachine::PowerPC64 => MachineFlags::Ppc64(Ppc64Flags(flags)),
+            ElfMachine::AVR => MachineFlags::Avr(AvrFlags(flags)),
+            ElfMachine::LoongArch => MachineFlags::LoongArch(LoongArchFlags(flags)),
+            _ => MachineFlags::Unknown(flags),
+        }
+    }
+}
+
+impl fmt::Display for MachineFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MachineFlags::Arm(flags) => flags.fmt(f),
+            MachineFlags::RiscV(flags) => flags.fmt(f),
+            MachineFlags::Mips(flags) => flags.fmt(f),
+            MachineFlags::Ppc64(flags) => flags.fmt(f),
+            MachineFlags::Avr(flags) => flags.fmt(f),
+            MachineFlags::LoongArch(flags) => flags.fmt(f),
+            MachineFlags::Unknown(flags) => write!(f, "{:#x}", flags),
+        }
+    }
+}
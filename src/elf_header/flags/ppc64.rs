@@ -0,0 +1,34 @@
+use core::fmt;
+
+const EF_PPC64_ABI: u32 = 0x3;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Ppc64Abi {
+    ElfV1,
+    ElfV2,
+}
+
+/// PowerPC64 `e_flags`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Ppc64Flags(pub u32);
+
+impl Ppc64Flags {
+    /// The ELF ABI version, or `None` if unspecified.
+    pub fn abi(&self) -> Option<Ppc64Abi> {
+        match self.0 & EF_PPC64_ABI {
+            1 => Some(Ppc64Abi::ElfV1),
+            2 => Some(Ppc64Abi::ElfV2),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Ppc64Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)?;
+        match self.0 & EF_PPC64_ABI {
+            0 => Ok(()),
+            n => write!(f, ", abiv{}", n),
+        }
+    }
+}
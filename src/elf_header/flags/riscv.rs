@@ -0,0 +1,68 @@
+use core::fmt;
+
+const EF_RISCV_RVC: u32 = 0x1;
+const EF_RISCV_FLOAT_ABI: u32 = 0x6;
+const EF_RISCV_RVE: u32 = 0x8;
+const EF_RISCV_TSO: u32 = 0x10;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RiscVFloatAbi {
+    Soft,
+    Single,
+    Double,
+    Quad,
+}
+
+/// RISC-V `e_flags`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RiscVFlags(pub u32);
+
+impl RiscVFlags {
+    /// Uses the compressed instruction extension.
+    pub fn rvc(&self) -> bool {
+        self.0 & EF_RISCV_RVC != 0
+    }
+
+    pub fn float_abi(&self) -> RiscVFloatAbi {
+        match self.0 & EF_RISCV_FLOAT_ABI {
+            0x0 => RiscVFloatAbi::Soft,
+            0x2 => RiscVFloatAbi::Single,
+            0x4 => RiscVFloatAbi::Double,
+            _ => RiscVFloatAbi::Quad,
+        }
+    }
+
+    /// Targets the RV32E base integer ISA.
+    pub fn rve(&self) -> bool {
+        self.0 & EF_RISCV_RVE != 0
+    }
+
+    /// Requires the RVTSO memory consistency model.
+    pub fn tso(&self) -> bool {
+        self.0 & EF_RISCV_TSO != 0
+    }
+}
+
+impl fmt::Display for RiscVFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)?;
+        if self.0 == 0 {
+            return Ok(());
+        }
+        if self.rvc() {
+            f.write_str(", RVC")?;
+        }
+        if self.rve() {
+            f.write_str(", RVE")?;
+        }
+        if self.tso() {
+            f.write_str(", TSO")?;
+        }
+        f.write_str(match self.float_abi() {
+            RiscVFloatAbi::Soft => ", soft-float ABI",
+            RiscVFloatAbi::Single => ", single-float ABI",
+            RiscVFloatAbi::Double => ", double-float ABI",
+            RiscVFloatAbi::Quad => ", quad-float ABI",
+        })
+    }
+}
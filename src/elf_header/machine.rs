@@ -0,0 +1,336 @@
+use super::ElfEndian;
+
+/// Defines `ElfMachine` from the `e_machine` registry, with the `EM_*` name
+/// and a description of each architecture.
+macro_rules! elf_machine {
+    ($($variant:ident = $value:expr, $name:expr, $description:expr,)*) => {
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        pub enum ElfMachine {
+            $(#[doc = $description] $variant,)*
+            MachineUnknown(u16),
+        }
+
+        impl From<u16> for ElfMachine {
+            fn from(n: u16) -> Self {
+                match n {
+                    $($value => ElfMachine::$variant,)*
+                    n => ElfMachine::MachineUnknown(n),
+                }
+            }
+        }
+
+        impl From<ElfMachine> for u16 {
+            fn from(machine: ElfMachine) -> Self {
+                match machine {
+                    $(ElfMachine::$variant => $value,)*
+                    ElfMachine::MachineUnknown(n) => n,
+                }
+            }
+        }
+
+        impl ElfMachine {
+            /// The `EM_*` constant name, e.g. `EM_X86_64`.
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(ElfMachine::$variant => Some($name),)*
+                    ElfMachine::MachineUnknown(_) => None,
+                }
+            }
+
+            /// A human readable description, e.g. `AMD x86-64 architecture`.
+            pub fn description(&self) -> Option<&'static str> {
+                match self {
+                    $(ElfMachine::$variant => Some($description),)*
+                    ElfMachine::MachineUnknown(_) => None,
+                }
+            }
+        }
+    };
+}
+
+elf_machine! {
+        Unknown = 0x00, "EM_NONE", "No machine",
+        M32 = 0x01, "EM_M32", "AT&T WE 32100",
+        SPARC = 0x02, "EM_SPARC", "SUN SPARC",
+        x86 = 0x03, "EM_386", "Intel 80386",
+        M68K = 0x04, "EM_68K", "Motorola m68k family",
+        M88K = 0x05, "EM_88K", "Motorola m88k family",
+        IAMCU = 0x06, "EM_IAMCU", "Intel MCU",
+        I860 = 0x07, "EM_860", "Intel 80860",
+        MIPS = 0x08, "EM_MIPS", "MIPS R3000 big-endian",
+        S370 = 0x09, "EM_S370", "IBM System/370",
+        MIPS_RS3_LE = 0x0A, "EM_MIPS_RS3_LE", "MIPS R3000 little-endian",
+        PARISC = 0x0F, "EM_PARISC", "HPPA",
+        VPP500 = 0x11, "EM_VPP500", "Fujitsu VPP500",
+        SPARC32PLUS = 0x12, "EM_SPARC32PLUS", "Sun's \"v8plus\"",
+        I960 = 0x13, "EM_960", "Intel 80960",
+        PowerPC = 0x14, "EM_PPC", "PowerPC",
+        PowerPC64 = 0x15, "EM_PPC64", "PowerPC 64-bit",
+        S390 = 0x16, "EM_S390", "IBM S390",
+        SPU = 0x17, "EM_SPU", "IBM SPU/SPC",
+        V800 = 0x24, "EM_V800", "NEC V800 series",
+        FR20 = 0x25, "EM_FR20", "Fujitsu FR20",
+        RH32 = 0x26, "EM_RH32", "TRW RH-32",
+        RCE = 0x27, "EM_RCE", "Motorola RCE",
+        ARM = 0x28, "EM_ARM", "ARM",
+        FakeAlpha = 0x29, "EM_FAKE_ALPHA", "Digital Alpha",
+        SuperH = 0x2A, "EM_SH", "Hitachi SH",
+        SPARCV9 = 0x2B, "EM_SPARCV9", "SPARC v9 64-bit",
+        TRICORE = 0x2C, "EM_TRICORE", "Siemens Tricore",
+        ARC = 0x2D, "EM_ARC", "Argonaut RISC Core",
+        H8_300 = 0x2E, "EM_H8_300", "Hitachi H8/300",
+        H8_300H = 0x2F, "EM_H8_300H", "Hitachi H8/300H",
+        H8S = 0x30, "EM_H8S", "Hitachi H8S",
+        H8_500 = 0x31, "EM_H8_500", "Hitachi H8/500",
+        IA_64 = 0x32, "EM_IA_64", "Intel Merced",
+        MIPS_X = 0x33, "EM_MIPS_X", "Stanford MIPS-X",
+        COLDFIRE = 0x34, "EM_COLDFIRE", "Motorola Coldfire",
+        M68HC12 = 0x35, "EM_68HC12", "Motorola M68HC12",
+        MMA = 0x36, "EM_MMA", "Fujitsu MMA Multimedia Accelerator",
+        PCP = 0x37, "EM_PCP", "Siemens PCP",
+        NCPU = 0x38, "EM_NCPU", "Sony nCPU embedded RISC",
+        NDR1 = 0x39, "EM_NDR1", "Denso NDR1 microprocessor",
+        STARCORE = 0x3A, "EM_STARCORE", "Motorola Star*Core processor",
+        ME16 = 0x3B, "EM_ME16", "Toyota ME16 processor",
+        ST100 = 0x3C, "EM_ST100", "STMicroelectronics ST100 processor",
+        TINYJ = 0x3D, "EM_TINYJ", "Advanced Logic Corp. Tinyj emb.fam",
+        x86_64 = 0x3E, "EM_X86_64", "AMD x86-64 architecture",
+        PDSP = 0x3F, "EM_PDSP", "Sony DSP Processor",
+        PDP10 = 0x40, "EM_PDP10", "Digital PDP-10",
+        PDP11 = 0x41, "EM_PDP11", "Digital PDP-11",
+        FX66 = 0x42, "EM_FX66", "Siemens FX66 microcontroller",
+        ST9PLUS = 0x43, "EM_ST9PLUS", "STMicroelectronics ST9+ 8/16 mc",
+        ST7 = 0x44, "EM_ST7", "STMicroelectronics ST7 8 bit mc",
+        M68HC16 = 0x45, "EM_68HC16", "Motorola MC68HC16 microcontroller",
+        M68HC11 = 0x46, "EM_68HC11", "Motorola MC68HC11 microcontroller",
+        M68HC08 = 0x47, "EM_68HC08", "Motorola MC68HC08 microcontroller",
+        M68HC05 = 0x48, "EM_68HC05", "Motorola MC68HC05 microcontroller",
+        SVX = 0x49, "EM_SVX", "Silicon Graphics SVx",
+        ST19 = 0x4A, "EM_ST19", "STMicroelectronics ST19 8 bit mc",
+        VAX = 0x4B, "EM_VAX", "Digital VAX",
+        CRIS = 0x4C, "EM_CRIS", "Axis Communications 32-bit emb.proc",
+        JAVELIN = 0x4D, "EM_JAVELIN", "Infineon Technologies 32-bit emb.proc",
+        FIREPATH = 0x4E, "EM_FIREPATH", "Element 14 64-bit DSP Processor",
+        ZSP = 0x4F, "EM_ZSP", "LSI Logic 16-bit DSP Processor",
+        MMIX = 0x50, "EM_MMIX", "Donald Knuth's educational 64-bit proc",
+        HUANY = 0x51, "EM_HUANY", "Harvard University machine-independent object files",
+        PRISM = 0x52, "EM_PRISM", "SiTera Prism",
+        AVR = 0x53, "EM_AVR", "Atmel AVR 8-bit microcontroller",
+        FR30 = 0x54, "EM_FR30", "Fujitsu FR30",
+        D10V = 0x55, "EM_D10V", "Mitsubishi D10V",
+        D30V = 0x56, "EM_D30V", "Mitsubishi D30V",
+        V850 = 0x57, "EM_V850", "NEC v850",
+        M32R = 0x58, "EM_M32R", "Mitsubishi M32R",
+        MN10300 = 0x59, "EM_MN10300", "Matsushita MN10300",
+        MN10200 = 0x5A, "EM_MN10200", "Matsushita MN10200",
+        PJ = 0x5B, "EM_PJ", "picoJava",
+        OpenRISC = 0x5C, "EM_OPENRISC", "OpenRISC 32-bit embedded processor",
+        ARCompact = 0x5D, "EM_ARC_COMPACT", "ARC International ARCompact",
+        Xtensa = 0x5E, "EM_XTENSA", "Tensilica Xtensa Architecture",
+        VIDEOCORE = 0x5F, "EM_VIDEOCORE", "Alphamosaic VideoCore",
+        TMM_GPP = 0x60, "EM_TMM_GPP", "Thompson Multimedia General Purpose Proc",
+        NS32K = 0x61, "EM_NS32K", "National Semi. 32000",
+        TPC = 0x62, "EM_TPC", "Tenor Network TPC",
+        SNP1K = 0x63, "EM_SNP1K", "Trebia SNP 1000",
+        ST200 = 0x64, "EM_ST200", "STMicroelectronics ST200",
+        IP2K = 0x65, "EM_IP2K", "Ubicom IP2xxx",
+        MAX = 0x66, "EM_MAX", "MAX processor",
+        CR = 0x67, "EM_CR", "National Semi. CompactRISC",
+        F2MC16 = 0x68, "EM_F2MC16", "Fujitsu F2MC16",
+        MSP430 = 0x69, "EM_MSP430", "Texas Instruments msp430",
+        BLACKFIN = 0x6A, "EM_BLACKFIN", "Analog Devices Blackfin DSP",
+        SE_C33 = 0x6B, "EM_SE_C33", "Seiko Epson S1C33 family",
+        SEP = 0x6C, "EM_SEP", "Sharp embedded microprocessor",
+        ARCA = 0x6D, "EM_ARCA", "Arca RISC",
+        UNICORE = 0x6E, "EM_UNICORE", "PKU-Unity & MPRC Peking Uni. mc series",
+        EXCESS = 0x6F, "EM_EXCESS", "eXcess configurable cpu",
+        DXP = 0x70, "EM_DXP", "Icera Semi. Deep Execution Processor",
+        Nios2 = 0x71, "EM_ALTERA_NIOS2", "Altera Nios II",
+        CRX = 0x72, "EM_CRX", "National Semi. CompactRISC CRX",
+        XGATE = 0x73, "EM_XGATE", "Motorola XGATE",
+        C166 = 0x74, "EM_C166", "Infineon C16x/XC16x",
+        M16C = 0x75, "EM_M16C", "Renesas M16C",
+        DSPIC30F = 0x76, "EM_DSPIC30F", "Microchip Technology dsPIC30F",
+        CE = 0x77, "EM_CE", "Freescale Communication Engine RISC",
+        M32C = 0x78, "EM_M32C", "Renesas M32C",
+        TSK3000 = 0x83, "EM_TSK3000", "Altium TSK3000",
+        RS08 = 0x84, "EM_RS08", "Freescale RS08",
+        SHARC = 0x85, "EM_SHARC", "Analog Devices SHARC family",
+        ECOG2 = 0x86, "EM_ECOG2", "Cyan Technology eCOG2",
+        SCORE7 = 0x87, "EM_SCORE7", "Sunplus S+core7 RISC",
+        DSP24 = 0x88, "EM_DSP24", "New Japan Radio (NJR) 24-bit DSP",
+        VIDEOCORE3 = 0x89, "EM_VIDEOCORE3", "Broadcom VideoCore III",
+        LATTICEMICO32 = 0x8A, "EM_LATTICEMICO32", "RISC for Lattice FPGA",
+        SE_C17 = 0x8B, "EM_SE_C17", "Seiko Epson C17",
+        TI_C6000 = 0x8C, "EM_TI_C6000", "Texas Instruments TMS320C6000 DSP",
+        TI_C2000 = 0x8D, "EM_TI_C2000", "Texas Instruments TMS320C2000 DSP",
+        TI_C5500 = 0x8E, "EM_TI_C5500", "Texas Instruments TMS320C55x DSP",
+        TI_ARP32 = 0x8F, "EM_TI_ARP32", "Texas Instruments App. Specific RISC",
+        TI_PRU = 0x90, "EM_TI_PRU", "Texas Instruments Prog. Realtime Unit",
+        MMDSP_PLUS = 0xA0, "EM_MMDSP_PLUS", "STMicroelectronics 64bit VLIW DSP",
+        CYPRESS_M8C = 0xA1, "EM_CYPRESS_M8C", "Cypress M8C",
+        R32C = 0xA2, "EM_R32C", "Renesas R32C",
+        TRIMEDIA = 0xA3, "EM_TRIMEDIA", "NXP Semi. TriMedia",
+        Hexagon = 0xA4, "EM_QDSP6", "QUALCOMM DSP6",
+        I8051 = 0xA5, "EM_8051", "Intel 8051 and variants",
+        STXP7X = 0xA6, "EM_STXP7X", "STMicroelectronics STxP7x",
+        NDS32 = 0xA7, "EM_NDS32", "Andes Tech. compact code emb. RISC",
+        ECOG1X = 0xA8, "EM_ECOG1X", "Cyan Technology eCOG1X",
+        MAXQ30 = 0xA9, "EM_MAXQ30", "Dallas Semi. MAXQ30 mc",
+        XIMO16 = 0xAA, "EM_XIMO16", "New Japan Radio (NJR) 16-bit DSP",
+        MANIK = 0xAB, "EM_MANIK", "M2000 Reconfigurable RISC",
+        CRAYNV2 = 0xAC, "EM_CRAYNV2", "Cray NV2 vector architecture",
+        RX = 0xAD, "EM_RX", "Renesas RX",
+        METAG = 0xAE, "EM_METAG", "Imagination Tech. META",
+        MCST_ELBRUS = 0xAF, "EM_MCST_ELBRUS", "MCST Elbrus",
+        ECOG16 = 0xB0, "EM_ECOG16", "Cyan Technology eCOG16",
+        CR16 = 0xB1, "EM_CR16", "National Semi. CompactRISC CR16",
+        ETPU = 0xB2, "EM_ETPU", "Freescale Extended Time Processing Unit",
+        SLE9X = 0xB3, "EM_SLE9X", "Infineon Tech. SLE9X",
+        L10M = 0xB4, "EM_L10M", "Intel L10M",
+        K10M = 0xB5, "EM_K10M", "Intel K10M",
+        AArch64 = 0xB7, "EM_AARCH64", "ARM AARCH64",
+        AVR32 = 0xB9, "EM_AVR32", "Atmel 32-bit microprocessor",
+        STM8 = 0xBA, "EM_STM8", "STMicroelectronics STM8",
+        TILE64 = 0xBB, "EM_TILE64", "Tilera TILE64",
+        TILEPRO = 0xBC, "EM_TILEPRO", "Tilera TILEPro",
+        MicroBlaze = 0xBD, "EM_MICROBLAZE", "Xilinx MicroBlaze",
+        CUDA = 0xBE, "EM_CUDA", "NVIDIA CUDA",
+        TILEGX = 0xBF, "EM_TILEGX", "Tilera TILE-Gx",
+        CLOUDSHIELD = 0xC0, "EM_CLOUDSHIELD", "CloudShield",
+        COREA_1ST = 0xC1, "EM_COREA_1ST", "KIPO-KAIST Core-A 1st gen",
+        COREA_2ND = 0xC2, "EM_COREA_2ND", "KIPO-KAIST Core-A 2nd gen",
+        ARCv2 = 0xC3, "EM_ARCV2", "Synopsys ARCv2 ISA",
+        OPEN8 = 0xC4, "EM_OPEN8", "Open8 RISC",
+        RL78 = 0xC5, "EM_RL78", "Renesas RL78",
+        VIDEOCORE5 = 0xC6, "EM_VIDEOCORE5", "Broadcom VideoCore V",
+        RL78KOR = 0xC7, "EM_78KOR", "Renesas 78KOR",
+        DSP56800EX = 0xC8, "EM_56800EX", "Freescale 56800EX DSC",
+        BA1 = 0xC9, "EM_BA1", "Beyond BA1",
+        BA2 = 0xCA, "EM_BA2", "Beyond BA2",
+        XCORE = 0xCB, "EM_XCORE", "XMOS xCORE",
+        MCHP_PIC = 0xCC, "EM_MCHP_PIC", "Microchip 8-bit PIC(r)",
+        INTELGT = 0xCD, "EM_INTELGT", "Intel Graphics Technology",
+        KM32 = 0xD2, "EM_KM32", "KM211 KM32",
+        KMX32 = 0xD3, "EM_KMX32", "KM211 KMX32",
+        EMX16 = 0xD4, "EM_EMX16", "KM211 KMX16",
+        EMX8 = 0xD5, "EM_EMX8", "KM211 KMX8",
+        KVARC = 0xD6, "EM_KVARC", "KM211 KVARC",
+        CDP = 0xD7, "EM_CDP", "Paneve CDP",
+        COGE = 0xD8, "EM_COGE", "Cognitive Smart Memory Processor",
+        COOL = 0xD9, "EM_COOL", "Bluechip CoolEngine",
+        NORC = 0xDA, "EM_NORC", "Nanoradio Optimized RISC",
+        CSR_KALIMBA = 0xDB, "EM_CSR_KALIMBA", "CSR Kalimba",
+        Z80 = 0xDC, "EM_Z80", "Zilog Z80",
+        VISIUM = 0xDD, "EM_VISIUM", "Controls and Data Services VISIUMcore",
+        FT32 = 0xDE, "EM_FT32", "FTDI Chip FT32",
+        MOXIE = 0xDF, "EM_MOXIE", "Moxie processor",
+        AMDGPU = 0xE0, "EM_AMDGPU", "AMD GPU",
+        RISC_V = 0xF3, "EM_RISCV", "RISC-V",
+        LANAI = 0xF4, "EM_LANAI", "Lanai 32-bit processor",
+        CEVA = 0xF5, "EM_CEVA", "CEVA Processor Architecture Family",
+        CEVA_X2 = 0xF6, "EM_CEVA_X2", "CEVA X2 Processor Family",
+        BPF = 0xF7, "EM_BPF", "Linux BPF -- in-kernel virtual machine",
+        GRAPHCORE_IPU = 0xF8, "EM_GRAPHCORE_IPU", "Graphcore Intelligent Processing Unit",
+        IMG1 = 0xF9, "EM_IMG1", "Imagination Technologies",
+        NFP = 0xFA, "EM_NFP", "Netronome Flow Processor",
+        VE = 0xFB, "EM_VE", "NEC Vector Engine",
+        CSKY = 0xFC, "EM_CSKY", "C-SKY",
+        ARC_COMPACT3_64 = 0xFD, "EM_ARC_COMPACT3_64", "Synopsys ARCv2.3 64-bit",
+        MCS6502 = 0xFE, "EM_MCS6502", "MOS Technology MCS 6502",
+        ARC_COMPACT3 = 0xFF, "EM_ARC_COMPACT3", "Synopsys ARCv2.3 32-bit",
+        KVX = 0x100, "EM_KVX", "Kalray VLIW core of the MPPA processor family",
+        WDC65816 = 0x101, "EM_65816", "WDC 65816/65C816",
+        LoongArch = 0x102, "EM_LOONGARCH", "LoongArch",
+        Alpha = 0x9026, "EM_ALPHA", "Digital Alpha",
+}
+
+impl ElfMachine {
+    /// Natural pointer width in bits, or `None` if it depends on `EI_CLASS`
+    /// or is not known.
+    pub fn pointer_width(&self) -> Option<u8> {
+        match self {
+            ElfMachine::AVR
+            | ElfMachine::MSP430
+            | ElfMachine::Z80
+            | ElfMachine::PDP11
+            | ElfMachine::M68HC11
+            | ElfMachine::M68HC12
+            | ElfMachine::MCS6502
+            | ElfMachine::I8051 => Some(16),
+            ElfMachine::x86
+            | ElfMachine::IAMCU
+            | ElfMachine::M68K
+            | ElfMachine::SPARC
+            | ElfMachine::SPARC32PLUS
+            | ElfMachine::PowerPC
+            | ElfMachine::ARM
+            | ElfMachine::Hexagon
+            | ElfMachine::Xtensa
+            | ElfMachine::ARC
+            | ElfMachine::ARCompact
+            | ElfMachine::ARCv2
+            | ElfMachine::CSKY
+            | ElfMachine::MicroBlaze
+            | ElfMachine::Nios2
+            | ElfMachine::OpenRISC
+            | ElfMachine::TI_C6000
+            | ElfMachine::BLACKFIN
+            | ElfMachine::VAX => Some(32),
+            ElfMachine::x86_64
+            | ElfMachine::AArch64
+            | ElfMachine::IA_64
+            | ElfMachine::PowerPC64
+            | ElfMachine::SPARCV9
+            | ElfMachine::Alpha
+            | ElfMachine::FakeAlpha
+            | ElfMachine::AMDGPU
+            | ElfMachine::CUDA
+            | ElfMachine::BPF
+            | ElfMachine::VE
+            | ElfMachine::KVX
+            | ElfMachine::TILEGX
+            | ElfMachine::MMIX => Some(64),
+            _ => None,
+        }
+    }
+
+    /// The usual byte order, or `None` if the architecture is commonly used
+    /// in both or it is not known.
+    pub fn default_endian(&self) -> Option<ElfEndian> {
+        match self {
+            ElfMachine::x86
+            | ElfMachine::x86_64
+            | ElfMachine::IAMCU
+            | ElfMachine::AArch64
+            | ElfMachine::ARM
+            | ElfMachine::RISC_V
+            | ElfMachine::LoongArch
+            | ElfMachine::IA_64
+            | ElfMachine::Alpha
+            | ElfMachine::FakeAlpha
+            | ElfMachine::MIPS_RS3_LE
+            | ElfMachine::AVR
+            | ElfMachine::MSP430
+            | ElfMachine::Hexagon
+            | ElfMachine::AMDGPU
+            | ElfMachine::CUDA
+            | ElfMachine::BPF
+            | ElfMachine::VE
+            | ElfMachine::CSKY
+            | ElfMachine::Nios2
+            | ElfMachine::VAX
+            | ElfMachine::Z80 => Some(ElfEndian::LittleEndian),
+            ElfMachine::SPARC
+            | ElfMachine::SPARC32PLUS
+            | ElfMachine::SPARCV9
+            | ElfMachine::M68K
+            | ElfMachine::S370
+            | ElfMachine::S390
+            | ElfMachine::PARISC
+            | ElfMachine::PowerPC
+            | ElfMachine::OpenRISC => Some(ElfEndian::BigEndian),
+            _ => None,
+        }
+    }
+}
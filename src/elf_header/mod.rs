@@ -1,8 +1,20 @@
+#[allow(clippy::module_inception)]
 mod elf_header;
 
 pub use elf_header::{ElfHeader32, ElfHeader64};
 
+mod flags;
+pub use flags::{
+    ArmFlags, ArmFloatAbi, AvrFlags, LoongArchFlags, LoongArchFloatAbi, MachineFlags, MipsAbi,
+    MipsArch, MipsFlags, Ppc64Abi, Ppc64Flags, RiscVFlags, RiscVFloatAbi,
+};
+
+mod machine;
+pub use machine::ElfMachine;
+
 pub const ELF_MAGIC: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+pub(crate) const EI_DATA: usize = 5;
+pub(crate) const EV_CURRENT: u8 = 1;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ElfClass {
@@ -116,43 +128,6 @@ impl From<u16> for ElfType {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum ElfMachine {
-    Unknown, // 0x00
-    SPARC,   // 0x02
-    x86,     // 0x03
-    MIPS,    // 0x08
-    PowerPC, // 0x14
-    S390,    // 0x16
-    ARM,     // 0x28
-    SuperH,  // 0x2A
-    IA_64,   // 0x32
-    x86_64,  // 0x3E
-    AArch64, // 0xB7
-    RISC_V,  // 0xF3
-    MachineUnknown(u16),
-}
-
-impl From<u16> for ElfMachine {
-    fn from(n: u16) -> Self {
-        match n {
-            0x00 => ElfMachine::Unknown,
-            0x02 => ElfMachine::SPARC,
-            0x03 => ElfMachine::x86,
-            0x08 => ElfMachine::MIPS,
-            0x14 => ElfMachine::PowerPC,
-            0x16 => ElfMachine::S390,
-            0x28 => ElfMachine::ARM,
-            0x2A => ElfMachine::SuperH,
-            0x32 => ElfMachine::IA_64,
-            0x3E => ElfMachine::x86_64,
-            0xB7 => ElfMachine::AArch64,
-            0xF3 => ElfMachine::RISC_V,
-            n => ElfMachine::MachineUnknown(n),
-        }
-    }
-}
-
 pub trait ElfHeaderRaw {
     fn class(&self) -> ElfClass;
 
@@ -178,6 +153,11 @@ pub trait ElfHeaderRaw {
 
     fn flags(&self) -> u32;
 
+    /// `e_flags` decoded according to the target machine.
+    fn machine_flags(&self) -> MachineFlags {
+        MachineFlags::new(self.machine(), self.flags())
+    }
+
     fn elf_header_size(&self) -> u16;
 
     fn program_header_entry_size(&self) -> u16;
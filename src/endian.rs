@@ -0,0 +1,155 @@
+use crate::elf_header::ElfEndian;
+use core::fmt;
+use core::marker::PhantomData;
+use num_traits::PrimInt;
+
+/// Byte order of the multi-byte fields in an ELF file, as given by
+/// `EI_DATA` in the ELF identification.
+///
+/// Raw program and section headers are parameterized by this trait, so the
+/// same structure can be read correctly on hosts of either byte order.
+pub trait Endianness: 'static {
+    const ENDIAN: ElfEndian;
+
+    /// Converts a value read from the file to host byte order.
+    fn read<T: PrimInt>(n: T) -> T;
+}
+
+#[derive(Debug)]
+pub enum LittleEndian {}
+
+impl Endianness for LittleEndian {
+    const ENDIAN: ElfEndian = ElfEndian::LittleEndian;
+
+    fn read<T: PrimInt>(n: T) -> T {
+        T::from_le(n)
+    }
+}
+
+#[derive(Debug)]
+pub enum BigEndian {}
+
+impl Endianness for BigEndian {
+    const ENDIAN: ElfEndian = ElfEndian::BigEndian;
+
+    fn read<T: PrimInt>(n: T) -> T {
+        T::from_be(n)
+    }
+}
+
+/// Structures that can be viewed in place from any byte buffer of their
+/// size.
+///
+/// # Safety
+///
+/// Implementors must have alignment 1 and no padding, and every bit
+/// pattern must be a valid value.
+pub unsafe trait Pod: Sized {}
+
+unsafe impl Pod for u8 {}
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+macro_rules! endian_int {
+    ($(#[$attr:meta])* $name:ident, $int:ty) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        pub struct $name<E: Endianness>([u8; core::mem::size_of::<$int>()], PhantomData<E>);
+
+        impl<E: Endianness> $name<E> {
+            /// Returns the value in host byte order.
+            pub fn get(&self) -> $int {
+                E::read(<$int>::from_ne_bytes(self.0))
+            }
+        }
+
+        impl<E: Endianness> Clone for $name<E> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<E: Endianness> Copy for $name<E> {}
+
+        impl<E: Endianness> fmt::Debug for $name<E> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+
+        unsafe impl<E: Endianness> Pod for $name<E> {}
+    };
+}
+
+endian_int!(
+    /// A `u16` stored in byte order `E`, with alignment 1.
+    U16,
+    u16
+);
+endian_int!(
+    /// A `u32` stored in byte order `E`, with alignment 1.
+    U32,
+    u32
+);
+endian_int!(
+    /// A `u64` stored in byte order `E`, with alignment 1.
+    U64,
+    u64
+);
+endian_int!(
+    /// An `i32` stored in byte order `E`, with alignment 1.
+    I32,
+    i32
+);
+endian_int!(
+    /// An `i64` stored in byte order `E`, with alignment 1.
+    I64,
+    i64
+);
+
+/// An address sized field: `U32` in ELF32 and `U64` in ELF64 structures.
+pub trait Word: Pod + Copy + fmt::Debug {
+    fn to_u64(&self) -> u64;
+}
+
+impl<E: Endianness> Word for U32<E> {
+    fn to_u64(&self) -> u64 {
+        self.get() as u64
+    }
+}
+
+impl<E: Endianness> Word for U64<E> {
+    fn to_u64(&self) -> u64 {
+        self.get()
+    }
+}
+
+/// Reads a `u16` at `offset` of `data` in the given byte order.
+pub(crate) fn read_u16(endian: ElfEndian, data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset.checked_add(2)?)?;
+    let bytes = [bytes[0], bytes[1]];
+    match endian {
+        ElfEndian::BigEndian => Some(u16::from_be_bytes(bytes)),
+        _ => Some(u16::from_le_bytes(bytes)),
+    }
+}
+
+/// Reads a `u32` at `offset` of `data` in the given byte order.
+pub(crate) fn read_u32(endian: ElfEndian, data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+    match endian {
+        ElfEndian::BigEndian => Some(u32::from_be_bytes(bytes)),
+        _ => Some(u32::from_le_bytes(bytes)),
+    }
+}
+
+/// Reads a `u64` at `offset` of `data` in the given byte order.
+pub(crate) fn read_u64(endian: ElfEndian, data: &[u8], offset: usize) -> Option<u64> {
+    let bytes = data.get(offset..offset.checked_add(8)?)?;
+    let mut word = [0; 8];
+    word.copy_from_slice(bytes);
+    match endian {
+        ElfEndian::BigEndian => Some(u64::from_be_bytes(word)),
+        _ => Some(u64::from_le_bytes(word)),
+    }
+}
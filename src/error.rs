@@ -0,0 +1,130 @@
+use crate::elf::Finding;
+use core::fmt;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The buffer is smaller than the ELF header.
+    BufferTooShort,
+    InvalidMagic,
+    /// `EI_CLASS` is neither `ELFCLASS32` nor `ELFCLASS64`, or does not match
+    /// the requested class.
+    InvalidClass,
+    /// `EI_DATA` is neither `ELFDATA2LSB` nor `ELFDATA2MSB`.
+    InvalidEndian(u8),
+    /// `e_phentsize` is smaller than the program header structure.
+    InvalidProgramHeaderEntrySize(u16),
+    /// `e_shentsize` is smaller than the section header structure.
+    InvalidSectionHeaderEntrySize(u16),
+    ProgramHeaderTableOutOfBounds {
+        offset: u64,
+        size: u64,
+    },
+    SectionHeaderTableOutOfBounds {
+        offset: u64,
+        size: u64,
+    },
+    ProgramHeaderIndexOutOfRange {
+        index: usize,
+        num: usize,
+    },
+    SectionHeaderIndexOutOfRange {
+        index: usize,
+        num: usize,
+    },
+    /// The file contents of a segment lie outside the buffer.
+    SegmentOutOfBounds {
+        offset: u64,
+        size: u64,
+    },
+    /// The contents of a section lie outside the buffer.
+    SectionOutOfBounds {
+        offset: u64,
+        size: u64,
+    },
+    /// A string offset lies outside its string table of `size` bytes.
+    InvalidStringOffset {
+        offset: u64,
+        size: u64,
+    },
+    /// The string at `offset` has no NUL terminator within its string table.
+    UnterminatedString {
+        offset: u64,
+    },
+    /// A compressed section header is truncated.
+    InvalidCompressionHeader,
+    /// The section is compressed with an algorithm that is unknown or whose
+    /// feature is disabled.
+    UnsupportedCompression(u32),
+    /// The compressed data is corrupt or does not match its uncompressed
+    /// size.
+    DecompressionFailed,
+    /// The DWARF debug info is malformed.
+    #[cfg(feature = "gimli")]
+    Dwarf(gimli::Error),
+    /// `validate` found a problem its policy rejects.
+    ValidationFailed(Finding),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::BufferTooShort => write!(f, "buffer too short for an ELF header"),
+            Error::InvalidMagic => write!(f, "invalid ELF magic"),
+            Error::InvalidClass => write!(f, "invalid or unexpected ELF class"),
+            Error::InvalidEndian(n) => write!(f, "unsupported data encoding {}", n),
+            Error::InvalidProgramHeaderEntrySize(n) => {
+                write!(f, "program header entry size {} is too small", n)
+            }
+            Error::InvalidSectionHeaderEntrySize(n) => {
+                write!(f, "section header entry size {} is too small", n)
+            }
+            Error::ProgramHeaderTableOutOfBounds { offset, size } => write!(
+                f,
+                "program header table at {:#x} ({:#x} bytes) is out of bounds",
+                offset, size
+            ),
+            Error::SectionHeaderTableOutOfBounds { offset, size } => write!(
+                f,
+                "section header table at {:#x} ({:#x} bytes) is out of bounds",
+                offset, size
+            ),
+            Error::ProgramHeaderIndexOutOfRange { index, num } => write!(
+                f,
+                "program header index {} out of range ({} entries)",
+                index, num
+            ),
+            Error::SectionHeaderIndexOutOfRange { index, num } => write!(
+                f,
+                "section header index {} out of range ({} entries)",
+                index, num
+            ),
+            Error::SegmentOutOfBounds { offset, size } => write!(
+                f,
+                "segment at {:#x} ({:#x} bytes) is out of bounds",
+                offset, size
+            ),
+            Error::SectionOutOfBounds { offset, size } => write!(
+                f,
+                "section at {:#x} ({:#x} bytes) is out of bounds",
+                offset, size
+            ),
+            Error::InvalidStringOffset { offset, size } => write!(
+                f,
+                "string offset {:#x} is outside a {:#x} byte string table",
+                offset, size
+            ),
+            Error::UnterminatedString { offset } => {
+                write!(f, "string at offset {:#x} is not NUL terminated", offset)
+            }
+            Error::InvalidCompressionHeader => write!(f, "invalid compression header"),
+            Error::UnsupportedCompression(n) => write!(f, "unsupported compression type {}", n),
+            Error::DecompressionFailed => write!(f, "decompression failed"),
+            #[cfg(feature = "gimli")]
+            Error::Dwarf(e) => write!(f, "invalid DWARF: {}", e),
+            Error::ValidationFailed(finding) => write!(f, "validation failed: {}", finding),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -23,34 +23,73 @@
 
 #[macro_use]
 extern crate bitflags;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "gimli")]
+pub extern crate gimli;
 extern crate num_traits;
+#[cfg(feature = "std")]
+extern crate std;
 
 use core::mem::size_of;
+mod dynamic;
 mod elf;
 mod elf_header;
+mod endian;
+mod error;
+mod note;
 mod program_header;
+mod relocation;
 mod section_header;
+mod symbol;
+mod version;
 
+pub use dynamic::{Dynamic32, Dynamic64, DynamicFlags, DynamicFlags1, DynamicRaw, DynamicTag};
+#[cfg(feature = "alloc")]
+pub use elf::SectionNameIndex;
+pub use elf::{gnu_hash, sysv_hash};
 pub use elf::{
-    Elf32, Elf64, ElfFile, ElfHeader, ProgramHeaderEntry, ProgramHeaderIter, SectionHeaderEntry,
-    SectionHeaderIter,
+    DebugAltLink, DebugLink, DynamicEntry, DynamicIter, DynamicStringIter, Elf32, Elf64, ElfFile,
+    ElfHeader, Finding, FindingKind, GnuHashTable, NoteEntry, NoteIter, ProgramHeaderEntry,
+    ProgramHeaderIter, RelocationEntry, RelocationIter, SectionHeaderEntry, SectionHeaderIter,
+    Severity, StringTable, StringTableIter, SymbolEntry, SymbolIter, SysvHashTable,
+    ValidationPolicy, ValidationReport, VerdauxIter, VerdefEntry, VerdefIter, VernauxEntry,
+    VernauxIter, VerneedEntry, VerneedIter, VersymTable, MAX_FINDINGS,
 };
+#[cfg(feature = "std")]
+pub use elf::{DebugFile, DebugFileResolver};
+#[cfg(feature = "gimli")]
+pub use elf::{DwarfData, DwarfReader};
+#[cfg(feature = "symbolize")]
+pub use elf::{Frame, Frames, Symbolizer};
 pub use elf_header::{
-    ElfAbi, ElfClass, ElfEndian, ElfHeader32, ElfHeader64, ElfHeaderRaw, ElfMachine, ElfType,
+    ArmFlags, ArmFloatAbi, AvrFlags, ElfAbi, ElfClass, ElfEndian, ElfHeader32, ElfHeader64,
+    ElfHeaderRaw, ElfMachine, ElfType, LoongArchFlags, LoongArchFloatAbi, MachineFlags, MipsAbi,
+    MipsArch, MipsFlags, Ppc64Abi, Ppc64Flags, RiscVFlags, RiscVFloatAbi,
+};
+pub use endian::{BigEndian, Endianness, LittleEndian, I32, I64, U16, U32, U64};
+pub use error::Error;
+pub use note::{
+    BuildId, BuildIdDebugPath, GnuAbiOs, GnuAbiTag, GnuNote, GnuNoteType, GnuProperty,
+    GnuPropertyIter, GnuPropertyType, ELF_NOTE_GNU,
 };
 pub use program_header::{
     ProgramHeader32, ProgramHeader64, ProgramHeaderFlags, ProgramHeaderRaw, ProgramType,
 };
+pub use relocation::{
+    Rel32, Rel64, Rela32, Rela64, RelocationAArch64, RelocationArm, RelocationI386, RelocationMips,
+    RelocationPpc64, RelocationRaw, RelocationRiscV, RelocationType, RelocationX86_64,
+};
 pub use section_header::{
-    SectionHeader32, SectionHeader64, SectionHeaderFlags, SectionHeaderRaw, SectionType,
+    ArmSectionFlags, CompressionHeader, CompressionType, MachineSectionFlags, SectionHeader32,
+    SectionHeader64, SectionHeaderFlags, SectionHeaderRaw, SectionType, X86_64SectionFlags,
+};
+pub use symbol::{
+    SectionIndex, Symbol32, Symbol64, SymbolBinding, SymbolRaw, SymbolType, SymbolVisibility,
+};
+pub use version::{
+    compare_versions, SymbolVersion, VersionFlags, VersionIndex, VER_NDX_GLOBAL, VER_NDX_LOCAL,
 };
-
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum Error {
-    BufferTooShort,
-    InvalidMagic,
-    InvalidClass,
-}
 
 #[derive(Debug)]
 pub enum Elf<'a> {
@@ -60,7 +99,7 @@ pub enum Elf<'a> {
 
 impl<'a> Elf<'a> {
     pub fn from_bytes(elf_buf: &'a [u8]) -> Result<Self, Error> {
-        if elf_buf.len() < size_of::<ElfHeader32>() {
+        if elf_buf.len() < size_of::<ElfHeader32<LittleEndian>>() {
             return Err(Error::BufferTooShort);
         }
 
@@ -70,11 +109,25 @@ impl<'a> Elf<'a> {
 
         let tmp_elf = Elf32::new(elf_buf);
         match tmp_elf.elf_header().class() {
-            ElfClass::Elf64 => Elf64::from_bytes(elf_buf).map(|e| Elf::Elf64(e)),
-            ElfClass::Elf32 => Elf32::from_bytes(elf_buf).map(|e| Elf::Elf32(e)),
+            ElfClass::Elf64 => Elf64::from_bytes(elf_buf).map(Elf::Elf64),
+            ElfClass::Elf32 => Elf32::from_bytes(elf_buf).map(Elf::Elf32),
             ElfClass::Unknown(_) => Err(Error::InvalidClass),
         }
     }
+
+    pub fn validate(&self, policy: ValidationPolicy) -> Result<ValidationReport, Error> {
+        match self {
+            Elf::Elf32(e) => e.validate(policy),
+            Elf::Elf64(e) => e.validate(policy),
+        }
+    }
+
+    pub fn validation_report(&self) -> ValidationReport {
+        match self {
+            Elf::Elf32(e) => e.validation_report(),
+            Elf::Elf64(e) => e.validation_report(),
+        }
+    }
 }
 
 impl<'a> ElfFile for Elf<'a> {
@@ -85,35 +138,35 @@ impl<'a> ElfFile for Elf<'a> {
         }
     }
 
-    fn elf_header(&self) -> ElfHeader {
+    fn elf_header(&self) -> ElfHeader<'_> {
         match self {
             Elf::Elf32(e) => e.elf_header(),
             Elf::Elf64(e) => e.elf_header(),
         }
     }
 
-    fn program_header_nth(&self, index: usize) -> Option<ProgramHeaderEntry> {
+    fn try_program_header_nth(&self, index: usize) -> Result<ProgramHeaderEntry<'_>, Error> {
         match self {
-            Elf::Elf32(e) => e.program_header_nth(index),
-            Elf::Elf64(e) => e.program_header_nth(index),
+            Elf::Elf32(e) => e.try_program_header_nth(index),
+            Elf::Elf64(e) => e.try_program_header_nth(index),
         }
     }
 
-    fn program_header_iter(&self) -> ProgramHeaderIter {
+    fn program_header_iter(&self) -> ProgramHeaderIter<'_> {
         match self {
             Elf::Elf32(e) => e.program_header_iter(),
             Elf::Elf64(e) => e.program_header_iter(),
         }
     }
 
-    fn section_header_nth(&self, index: usize) -> Option<SectionHeaderEntry> {
+    fn try_section_header_nth(&self, index: usize) -> Result<SectionHeaderEntry<'_>, Error> {
         match self {
-            Elf::Elf32(e) => e.section_header_nth(index),
-            Elf::Elf64(e) => e.section_header_nth(index),
+            Elf::Elf32(e) => e.try_section_header_nth(index),
+            Elf::Elf64(e) => e.try_section_header_nth(index),
         }
     }
 
-    fn section_header_iter(&self) -> SectionHeaderIter {
+    fn section_header_iter(&self) -> SectionHeaderIter<'_> {
         match self {
             Elf::Elf32(e) => e.section_header_iter(),
             Elf::Elf64(e) => e.section_header_iter(),
@@ -0,0 +1,47 @@
+use core::fmt;
+
+/// A GNU build-id, displayed as lowercase hex without allocating.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BuildId<'a>(&'a [u8]);
+
+impl<'a> BuildId<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Path of the separate debug file relative to a debug-file directory,
+    /// e.g. `.build-id/ab/cdef0123.debug`. `None` for build-ids shorter than
+    /// two bytes.
+    pub fn debug_path(&self) -> Option<BuildIdDebugPath<'a>> {
+        match self.0.len() {
+            0 | 1 => None,
+            _ => Some(BuildIdDebugPath(self.0)),
+        }
+    }
+}
+
+impl<'a> fmt::Display for BuildId<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+/// The `.build-id/xx/yyyy.debug` path of a build-id, see
+/// [`BuildId::debug_path`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BuildIdDebugPath<'a>(&'a [u8]);
+
+impl<'a> fmt::Display for BuildIdDebugPath<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            ".build-id/{}/{}.debug",
+            BuildId(&self.0[..1]),
+            BuildId(&self.0[1..])
+        )
+    }
+}
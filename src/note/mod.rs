@@ -0,0 +1,227 @@
+mod build_id;
+
+pub use build_id::{BuildId, BuildIdDebugPath};
+
+use crate::elf_header::{ElfClass, ElfEndian};
+use crate::endian::read_u32;
+
+/// Owner name of the notes defined by the GNU toolchain.
+pub const ELF_NOTE_GNU: &[u8] = b"GNU";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GnuNoteType {
+    NT_GNU_ABI_TAG,         // 1
+    NT_GNU_HWCAP,           // 2
+    NT_GNU_BUILD_ID,        // 3
+    NT_GNU_GOLD_VERSION,    // 4
+    NT_GNU_PROPERTY_TYPE_0, // 5
+    Unknown(u32),
+}
+
+impl From<u32> for GnuNoteType {
+    fn from(n: u32) -> Self {
+        match n {
+            1 => GnuNoteType::NT_GNU_ABI_TAG,
+            2 => GnuNoteType::NT_GNU_HWCAP,
+            3 => GnuNoteType::NT_GNU_BUILD_ID,
+            4 => GnuNoteType::NT_GNU_GOLD_VERSION,
+            5 => GnuNoteType::NT_GNU_PROPERTY_TYPE_0,
+            n => GnuNoteType::Unknown(n),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GnuAbiOs {
+    ELF_NOTE_OS_LINUX,    // 0
+    ELF_NOTE_OS_GNU,      // 1
+    ELF_NOTE_OS_SOLARIS2, // 2
+    ELF_NOTE_OS_FREEBSD,  // 3
+    Unknown(u32),
+}
+
+impl From<u32> for GnuAbiOs {
+    fn from(n: u32) -> Self {
+        match n {
+            0 => GnuAbiOs::ELF_NOTE_OS_LINUX,
+            1 => GnuAbiOs::ELF_NOTE_OS_GNU,
+            2 => GnuAbiOs::ELF_NOTE_OS_SOLARIS2,
+            3 => GnuAbiOs::ELF_NOTE_OS_FREEBSD,
+            n => GnuAbiOs::Unknown(n),
+        }
+    }
+}
+
+/// Descriptor of a `NT_GNU_ABI_TAG` note: the OS and the earliest kernel
+/// version the binary runs on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GnuAbiTag {
+    pub os: GnuAbiOs,
+    pub major: u32,
+    pub minor: u32,
+    pub subminor: u32,
+}
+
+const GNU_PROPERTY_UINT32_AND_LO: u32 = 0xb0000000;
+const GNU_PROPERTY_UINT32_AND_HI: u32 = 0xb0007fff;
+const GNU_PROPERTY_UINT32_OR_LO: u32 = 0xb0008000;
+const GNU_PROPERTY_UINT32_OR_HI: u32 = 0xb000ffff;
+const GNU_PROPERTY_LOPROC: u32 = 0xc0000000;
+const GNU_PROPERTY_HIPROC: u32 = 0xdfffffff;
+const GNU_PROPERTY_LOUSER: u32 = 0xe0000000;
+const GNU_PROPERTY_HIUSER: u32 = 0xffffffff;
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GnuPropertyType {
+    GNU_PROPERTY_STACK_SIZE,           // 1
+    GNU_PROPERTY_NO_COPY_ON_PROTECTED, // 2
+    GNU_PROPERTY_1_NEEDED,             // 0xb0008000
+    Uint32And(u32),
+    Uint32Or(u32),
+    ProcessorSpecific(u32),
+    ApplicationSpecific(u32),
+    Unknown(u32),
+}
+
+impl From<u32> for GnuPropertyType {
+    fn from(n: u32) -> Self {
+        match n {
+            1 => GnuPropertyType::GNU_PROPERTY_STACK_SIZE,
+            2 => GnuPropertyType::GNU_PROPERTY_NO_COPY_ON_PROTECTED,
+            0xb0008000 => GnuPropertyType::GNU_PROPERTY_1_NEEDED,
+            x @ GNU_PROPERTY_UINT32_AND_LO..=GNU_PROPERTY_UINT32_AND_HI => {
+                GnuPropertyType::Uint32And(x)
+            }
+            x @ GNU_PROPERTY_UINT32_OR_LO..=GNU_PROPERTY_UINT32_OR_HI => {
+                GnuPropertyType::Uint32Or(x)
+            }
+            x @ GNU_PROPERTY_LOPROC..=GNU_PROPERTY_HIPROC => GnuPropertyType::ProcessorSpecific(x),
+            x @ GNU_PROPERTY_LOUSER..=GNU_PROPERTY_HIUSER => {
+                GnuPropertyType::ApplicationSpecific(x)
+            }
+            n => GnuPropertyType::Unknown(n),
+        }
+    }
+}
+
+/// One property of a `NT_GNU_PROPERTY_TYPE_0` note.
+pub struct GnuProperty<'a> {
+    endian: ElfEndian,
+    pr_type: u32,
+    data: &'a [u8],
+}
+
+impl<'a> GnuProperty<'a> {
+    pub fn pr_type(&self) -> GnuPropertyType {
+        self.pr_type.into()
+    }
+
+    /// Raw property type. Processor-specific types such as
+    /// `GNU_PROPERTY_X86_FEATURE_1_AND` are only meaningful with the machine.
+    pub fn raw_type(&self) -> u32 {
+        self.pr_type
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// The property value, for the common properties holding one `u32`.
+    pub fn value_u32(&self) -> Option<u32> {
+        match self.data.len() {
+            4 => read_u32(self.endian, self.data, 0),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> core::fmt::Debug for GnuProperty<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GNU Property")
+            .field("type", &self.pr_type())
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+/// Iterates the properties of a `NT_GNU_PROPERTY_TYPE_0` descriptor, which
+/// are padded to 8 bytes in ELF64 and 4 bytes in ELF32.
+#[derive(Clone)]
+pub struct GnuPropertyIter<'a> {
+    endian: ElfEndian,
+    align: usize,
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> GnuPropertyIter<'a> {
+    pub fn new(endian: ElfEndian, class: ElfClass, data: &'a [u8]) -> Self {
+        let align = match class {
+            ElfClass::Elf64 => 8,
+            _ => 4,
+        };
+        Self {
+            endian,
+            align,
+            data,
+            offset: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for GnuPropertyIter<'a> {
+    type Item = GnuProperty<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pr_type = read_u32(self.endian, self.data, self.offset)?;
+        let pr_datasz = read_u32(self.endian, self.data, self.offset + 4)? as usize;
+        let start = self.offset + 8;
+        let data = self.data.get(start..start.checked_add(pr_datasz)?)?;
+        self.offset = align_up(start + pr_datasz, self.align);
+        Some(GnuProperty {
+            endian: self.endian,
+            pr_type,
+            data,
+        })
+    }
+}
+
+/// A note owned by `"GNU"`, decoded by its type.
+#[derive(Debug)]
+pub enum GnuNote<'a> {
+    AbiTag(GnuAbiTag),
+    BuildId(&'a [u8]),
+    GoldVersion(&'a [u8]),
+    Property(GnuPropertyIter<'a>),
+}
+
+impl<'a> GnuNote<'a> {
+    pub fn parse(endian: ElfEndian, class: ElfClass, n_type: u32, desc: &'a [u8]) -> Option<Self> {
+        match n_type.into() {
+            GnuNoteType::NT_GNU_ABI_TAG => Some(GnuNote::AbiTag(GnuAbiTag {
+                os: read_u32(endian, desc, 0)?.into(),
+                major: read_u32(endian, desc, 4)?,
+                minor: read_u32(endian, desc, 8)?,
+                subminor: read_u32(endian, desc, 12)?,
+            })),
+            GnuNoteType::NT_GNU_BUILD_ID => Some(GnuNote::BuildId(desc)),
+            GnuNoteType::NT_GNU_GOLD_VERSION => {
+                let version = desc.split(|&x| x == b'\0').next()?;
+                Some(GnuNote::GoldVersion(version))
+            }
+            GnuNoteType::NT_GNU_PROPERTY_TYPE_0 => {
+                Some(GnuNote::Property(GnuPropertyIter::new(endian, class, desc)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> core::fmt::Debug for GnuPropertyIter<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+pub(crate) fn align_up(n: usize, align: usize) -> usize {
+    (n + align - 1) & !(align - 1)
+}
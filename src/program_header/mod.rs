@@ -1,3 +1,5 @@
+use crate::elf_header::ElfMachine;
+
 mod program_header32;
 mod program_header64;
 
@@ -9,6 +11,10 @@ const HIOS: u32 = 0x6FFFFFFF;
 const LOPROC: u32 = 0x70000000;
 const HIPROC: u32 = 0x7FFFFFFF;
 
+/// `e_phnum` value marking that the real number of program headers is held
+/// in `sh_info` of section header 0.
+pub(crate) const PN_XNUM: u16 = 0xffff;
+
 bitflags! {
     /// The flags of an ELF program header. Always 32 bit long, also
     /// for 64-bit ELFs.
@@ -22,6 +28,8 @@ bitflags! {
     }
 }
 
+/// Segment type. Processor-specific types are only named by
+/// `ProgramType::new`, as their meaning depends on the target machine.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ProgramType {
     NULL,                   // 0x00000000,
@@ -31,6 +39,33 @@ pub enum ProgramType {
     NOTE,                   // 0x00000004,
     SHLIB,                  // 0x00000005,
     PHDR,                   // 0x00000006,
+    TLS,                    // 0x00000007,
+    GNU_EH_FRAME,           // 0x6474E550,
+    GNU_STACK,              // 0x6474E551,
+    GNU_RELRO,              // 0x6474E552,
+    GNU_PROPERTY,           // 0x6474E553,
+    GNU_SFRAME,             // 0x6474E554,
+    SUNW_UNWIND,            // 0x6464E550,
+    SUNWBSS,                // 0x6FFFFFFA,
+    SUNWSTACK,              // 0x6FFFFFFB,
+    OPENBSD_MUTABLE,        // 0x65A3DBE5,
+    OPENBSD_RANDOMIZE,      // 0x65A3DBE6,
+    OPENBSD_WXNEEDED,       // 0x65A3DBE7,
+    OPENBSD_NOBTCFI,        // 0x65A3DBE8,
+    OPENBSD_SYSCALLS,       // 0x65A3DBE9,
+    OPENBSD_BOOTDATA,       // 0x65A41BE6,
+    ARM_ARCHEXT,            // 0x70000000,
+    ARM_EXIDX,              // 0x70000001,
+    AARCH64_MEMTAG_MTE,     // 0x70000002,
+    MIPS_REGINFO,           // 0x70000000,
+    MIPS_RTPROC,            // 0x70000001,
+    MIPS_OPTIONS,           // 0x70000002,
+    MIPS_ABIFLAGS,          // 0x70000003,
+    RISCV_ATTRIBUTES,       // 0x70000003,
+    IA_64_ARCHEXT,          // 0x70000000,
+    IA_64_UNWIND,           // 0x70000001,
+    PARISC_ARCHEXT,         // 0x70000000,
+    PARISC_UNWIND,          // 0x70000001,
     OsSpecific(u32),        // 0x60000000 - 0x6FFFFFFF,
     ProcessorSpecific(u32), // 0x70000000 - 0x7FFFFFFF,
 
@@ -47,6 +82,21 @@ impl From<u32> for ProgramType {
             0x00000004 => ProgramType::NOTE,
             0x00000005 => ProgramType::SHLIB,
             0x00000006 => ProgramType::PHDR,
+            0x00000007 => ProgramType::TLS,
+            0x6474E550 => ProgramType::GNU_EH_FRAME,
+            0x6474E551 => ProgramType::GNU_STACK,
+            0x6474E552 => ProgramType::GNU_RELRO,
+            0x6474E553 => ProgramType::GNU_PROPERTY,
+            0x6474E554 => ProgramType::GNU_SFRAME,
+            0x6464E550 => ProgramType::SUNW_UNWIND,
+            0x6FFFFFFA => ProgramType::SUNWBSS,
+            0x6FFFFFFB => ProgramType::SUNWSTACK,
+            0x65A3DBE5 => ProgramType::OPENBSD_MUTABLE,
+            0x65A3DBE6 => ProgramType::OPENBSD_RANDOMIZE,
+            0x65A3DBE7 => ProgramType::OPENBSD_WXNEEDED,
+            0x65A3DBE8 => ProgramType::OPENBSD_NOBTCFI,
+            0x65A3DBE9 => ProgramType::OPENBSD_SYSCALLS,
+            0x65A41BE6 => ProgramType::OPENBSD_BOOTDATA,
             x @ LOOS..=HIOS => ProgramType::OsSpecific(x),
             x @ LOPROC..=HIPROC => ProgramType::ProcessorSpecific(x),
             x => ProgramType::Unknown(x),
@@ -54,8 +104,35 @@ impl From<u32> for ProgramType {
     }
 }
 
+impl ProgramType {
+    /// Decodes `p_type`, resolving processor-specific types for `machine`.
+    pub fn new(machine: ElfMachine, n: u32) -> Self {
+        match (machine, n) {
+            (ElfMachine::ARM, 0x70000000) => ProgramType::ARM_ARCHEXT,
+            (ElfMachine::ARM, 0x70000001) => ProgramType::ARM_EXIDX,
+            (ElfMachine::AArch64, 0x70000002) => ProgramType::AARCH64_MEMTAG_MTE,
+            (ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE, 0x70000000) => ProgramType::MIPS_REGINFO,
+            (ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE, 0x70000001) => ProgramType::MIPS_RTPROC,
+            (ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE, 0x70000002) => ProgramType::MIPS_OPTIONS,
+            (ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE, 0x70000003) => ProgramType::MIPS_ABIFLAGS,
+            (ElfMachine::RISC_V, 0x70000003) => ProgramType::RISCV_ATTRIBUTES,
+            (ElfMachine::IA_64, 0x70000000) => ProgramType::IA_64_ARCHEXT,
+            (ElfMachine::IA_64, 0x70000001) => ProgramType::IA_64_UNWIND,
+            (ElfMachine::PARISC, 0x70000000) => ProgramType::PARISC_ARCHEXT,
+            (ElfMachine::PARISC, 0x70000001) => ProgramType::PARISC_UNWIND,
+            (_, n) => n.into(),
+        }
+    }
+}
+
 pub trait ProgramHeaderRaw {
-    fn ph_type(&self) -> ProgramType;
+    fn raw_type(&self) -> u32;
+
+    /// `p_type` without processor-specific types, which need the target
+    /// machine. `ProgramHeaderEntry::resolved_type` resolves those too.
+    fn ph_type(&self) -> ProgramType {
+        self.raw_type().into()
+    }
 
     fn flags(&self) -> ProgramHeaderFlags;
 
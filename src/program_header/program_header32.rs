@@ -1,49 +1,51 @@
-use crate::program_header::{ProgramHeaderFlags, ProgramHeaderRaw, ProgramType};
-use core::ptr::read_unaligned;
+use crate::endian::{Endianness, Pod, U32};
+use crate::program_header::{ProgramHeaderFlags, ProgramHeaderRaw};
 
 #[derive(Debug)]
 #[repr(C)]
-pub struct ProgramHeader32 {
-    p_type: u32,
-    p_offset: u32,
-    p_vaddr: u32,
-    p_paddr: u32,
-    p_filesz: u32,
-    p_memsz: u32,
-    p_flags: ProgramHeaderFlags,
-    p_align: u32,
+pub struct ProgramHeader32<E: Endianness> {
+    p_type: U32<E>,
+    p_offset: U32<E>,
+    p_vaddr: U32<E>,
+    p_paddr: U32<E>,
+    p_filesz: U32<E>,
+    p_memsz: U32<E>,
+    p_flags: U32<E>,
+    p_align: U32<E>,
 }
 
-impl ProgramHeaderRaw for ProgramHeader32 {
-    fn ph_type(&self) -> ProgramType {
-        unsafe { read_unaligned(&self.p_type).into() }
+unsafe impl<E: Endianness> Pod for ProgramHeader32<E> {}
+
+impl<E: Endianness> ProgramHeaderRaw for ProgramHeader32<E> {
+    fn raw_type(&self) -> u32 {
+        self.p_type.get()
     }
 
     fn flags(&self) -> ProgramHeaderFlags {
-        unsafe { read_unaligned(&self.p_flags) }
+        ProgramHeaderFlags::from_bits_retain(self.p_flags.get())
     }
 
     fn offset(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_offset) as u64 }
+        self.p_offset.get() as u64
     }
 
     fn vaddr(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_vaddr) as u64 }
+        self.p_vaddr.get() as u64
     }
 
     fn paddr(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_paddr) as u64 }
+        self.p_paddr.get() as u64
     }
 
     fn filesz(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_filesz) as u64 }
+        self.p_filesz.get() as u64
     }
 
     fn memsz(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_memsz) as u64 }
+        self.p_memsz.get() as u64
     }
 
     fn align(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_align) as u64 }
+        self.p_align.get() as u64
     }
 }
@@ -1,49 +1,51 @@
-use crate::program_header::{ProgramHeaderFlags, ProgramHeaderRaw, ProgramType};
-use core::ptr::read_unaligned;
+use crate::endian::{Endianness, Pod, U32, U64};
+use crate::program_header::{ProgramHeaderFlags, ProgramHeaderRaw};
 
 #[derive(Debug)]
 #[repr(C)]
-pub struct ProgramHeader64 {
-    p_type: u32,
-    p_flags: ProgramHeaderFlags,
-    p_offset: u64,
-    p_vaddr: u64,
-    p_paddr: u64,
-    p_filesz: u64,
-    p_memsz: u64,
-    p_align: u64,
+pub struct ProgramHeader64<E: Endianness> {
+    p_type: U32<E>,
+    p_flags: U32<E>,
+    p_offset: U64<E>,
+    p_vaddr: U64<E>,
+    p_paddr: U64<E>,
+    p_filesz: U64<E>,
+    p_memsz: U64<E>,
+    p_align: U64<E>,
 }
 
-impl ProgramHeaderRaw for ProgramHeader64 {
-    fn ph_type(&self) -> ProgramType {
-        unsafe { read_unaligned(&self.p_type).into() }
+unsafe impl<E: Endianness> Pod for ProgramHeader64<E> {}
+
+impl<E: Endianness> ProgramHeaderRaw for ProgramHeader64<E> {
+    fn raw_type(&self) -> u32 {
+        self.p_type.get()
     }
 
     fn flags(&self) -> ProgramHeaderFlags {
-        unsafe { read_unaligned(&self.p_flags) }
+        ProgramHeaderFlags::from_bits_retain(self.p_flags.get())
     }
 
     fn offset(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_offset) }
+        self.p_offset.get()
     }
 
     fn vaddr(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_vaddr) }
+        self.p_vaddr.get()
     }
 
     fn paddr(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_paddr) }
+        self.p_paddr.get()
     }
 
     fn filesz(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_filesz) }
+        self.p_filesz.get()
     }
 
     fn memsz(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_memsz) }
+        self.p_memsz.get()
     }
 
     fn align(&self) -> u64 {
-        unsafe { read_unaligned(&self.p_align) }
+        self.p_align.get()
     }
 }
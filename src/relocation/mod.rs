@@ -0,0 +1,110 @@
+use crate::elf_header::ElfMachine;
+use core::fmt;
+
+/// Defines a machine-specific relocation type enum, whose `Display` prints
+/// the canonical `R_*` name.
+macro_rules! relocation_type {
+    ($(#[$attr:meta])* $name:ident { $($variant:ident = $value:expr,)* }) => {
+        $(#[$attr])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        pub enum $name {
+            $($variant,)*
+            Unknown(u32),
+        }
+
+        impl From<u32> for $name {
+            fn from(n: u32) -> Self {
+                match n {
+                    $($value => $name::$variant,)*
+                    n => $name::Unknown(n),
+                }
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    $($name::$variant => f.pad(stringify!($variant)),)*
+                    $name::Unknown(n) => write!(f, "<unknown>: {:#x}", n),
+                }
+            }
+        }
+    };
+}
+
+mod aarch64;
+mod arm;
+mod i386;
+mod mips;
+mod ppc64;
+mod relocation32;
+mod relocation64;
+mod riscv;
+mod x86_64;
+
+pub use aarch64::RelocationAArch64;
+pub use arm::RelocationArm;
+pub use i386::RelocationI386;
+pub use mips::RelocationMips;
+pub use ppc64::RelocationPpc64;
+pub use relocation32::{Rel32, Rela32};
+pub use relocation64::{Rel64, Rela64};
+pub use riscv::RelocationRiscV;
+pub use x86_64::RelocationX86_64;
+
+/// A relocation type decoded according to the target machine of the file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RelocationType {
+    X86_64(RelocationX86_64),
+    I386(RelocationI386),
+    AArch64(RelocationAArch64),
+    Arm(RelocationArm),
+    RiscV(RelocationRiscV),
+    Ppc64(RelocationPpc64),
+    Mips(RelocationMips),
+    Unknown(u32),
+}
+
+impl RelocationType {
+    pub fn new(machine: ElfMachine, r_type: u32) -> Self {
+        match machine {
+            ElfMachine::x86_64 => RelocationType::X86_64(r_type.into()),
+            ElfMachine::x86 => RelocationType::I386(r_type.into()),
+            ElfMachine::AArch64 => RelocationType::AArch64(r_type.into()),
+            ElfMachine::ARM => RelocationType::Arm(r_type.into()),
+            ElfMachine::RISC_V => RelocationType::RiscV(r_type.into()),
+            ElfMachine::PowerPC64 => RelocationType::Ppc64(r_type.into()),
+            ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE => RelocationType::Mips(r_type.into()),
+            _ => RelocationType::Unknown(r_type),
+        }
+    }
+}
+
+impl fmt::Display for RelocationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RelocationType::X86_64(t) => t.fmt(f),
+            RelocationType::I386(t) => t.fmt(f),
+            RelocationType::AArch64(t) => t.fmt(f),
+            RelocationType::Arm(t) => t.fmt(f),
+            RelocationType::RiscV(t) => t.fmt(f),
+            RelocationType::Ppc64(t) => t.fmt(f),
+            RelocationType::Mips(t) => t.fmt(f),
+            RelocationType::Unknown(n) => write!(f, "<unknown>: {:#x}", n),
+        }
+    }
+}
+
+pub trait RelocationRaw {
+    fn offset(&self) -> u64;
+
+    fn info(&self) -> u64;
+
+    /// Explicit addend of a `SHT_RELA` entry, `None` for `SHT_REL` entries
+    /// whose addend is stored at the relocated location.
+    fn addend(&self) -> Option<i64>;
+
+    fn sym_index(&self) -> u32;
+
+    fn r_type(&self) -> u32;
+}
@@ -0,0 +1,65 @@
+use crate::endian::{Endianness, Pod, I32, U32};
+use crate::relocation::RelocationRaw;
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct Rel32<E: Endianness> {
+    r_offset: U32<E>,
+    r_info: U32<E>,
+}
+
+unsafe impl<E: Endianness> Pod for Rel32<E> {}
+
+impl<E: Endianness> RelocationRaw for Rel32<E> {
+    fn offset(&self) -> u64 {
+        self.r_offset.get() as u64
+    }
+
+    fn info(&self) -> u64 {
+        self.r_info.get() as u64
+    }
+
+    fn addend(&self) -> Option<i64> {
+        None
+    }
+
+    fn sym_index(&self) -> u32 {
+        (self.info() >> 8) as u32
+    }
+
+    fn r_type(&self) -> u32 {
+        (self.info() & 0xff) as u32
+    }
+}
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct Rela32<E: Endianness> {
+    r_offset: U32<E>,
+    r_info: U32<E>,
+    r_addend: I32<E>,
+}
+
+unsafe impl<E: Endianness> Pod for Rela32<E> {}
+
+impl<E: Endianness> RelocationRaw for Rela32<E> {
+    fn offset(&self) -> u64 {
+        self.r_offset.get() as u64
+    }
+
+    fn info(&self) -> u64 {
+        self.r_info.get() as u64
+    }
+
+    fn addend(&self) -> Option<i64> {
+        Some(self.r_addend.get() as i64)
+    }
+
+    fn sym_index(&self) -> u32 {
+        (self.info() >> 8) as u32
+    }
+
+    fn r_type(&self) -> u32 {
+        (self.info() & 0xff) as u32
+    }
+}
@@ -0,0 +1,65 @@
+use crate::endian::{Endianness, Pod, I64, U64};
+use crate::relocation::RelocationRaw;
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct Rel64<E: Endianness> {
+    r_offset: U64<E>,
+    r_info: U64<E>,
+}
+
+unsafe impl<E: Endianness> Pod for Rel64<E> {}
+
+impl<E: Endianness> RelocationRaw for Rel64<E> {
+    fn offset(&self) -> u64 {
+        self.r_offset.get()
+    }
+
+    fn info(&self) -> u64 {
+        self.r_info.get()
+    }
+
+    fn addend(&self) -> Option<i64> {
+        None
+    }
+
+    fn sym_index(&self) -> u32 {
+        (self.info() >> 32) as u32
+    }
+
+    fn r_type(&self) -> u32 {
+        self.info() as u32
+    }
+}
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct Rela64<E: Endianness> {
+    r_offset: U64<E>,
+    r_info: U64<E>,
+    r_addend: I64<E>,
+}
+
+unsafe impl<E: Endianness> Pod for Rela64<E> {}
+
+impl<E: Endianness> RelocationRaw for Rela64<E> {
+    fn offset(&self) -> u64 {
+        self.r_offset.get()
+    }
+
+    fn info(&self) -> u64 {
+        self.r_info.get()
+    }
+
+    fn addend(&self) -> Option<i64> {
+        Some(self.r_addend.get())
+    }
+
+    fn sym_index(&self) -> u32 {
+        (self.info() >> 32) as u32
+    }
+
+    fn r_type(&self) -> u32 {
+        self.info() as u32
+    }
+}
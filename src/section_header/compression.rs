@@ -0,0 +1,127 @@
+use crate::elf_header::{ElfClass, ElfEndian};
+use crate::endian::{read_u32, read_u64};
+#[cfg(any(feature = "zlib", feature = "zstd"))]
+use crate::Error;
+#[cfg(any(feature = "zlib", feature = "zstd"))]
+use alloc::vec::Vec;
+#[cfg(any(feature = "zlib", feature = "zstd"))]
+use core::convert::TryFrom;
+
+const ELFCOMPRESS_LOOS: u32 = 0x60000000;
+const ELFCOMPRESS_HIOS: u32 = 0x6fffffff;
+const ELFCOMPRESS_LOPROC: u32 = 0x70000000;
+const ELFCOMPRESS_HIPROC: u32 = 0x7fffffff;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CompressionType {
+    ELFCOMPRESS_ZLIB, // 1
+    ELFCOMPRESS_ZSTD, // 2
+    OsSpecific(u32),
+    ProcessorSpecific(u32),
+    Unknown(u32),
+}
+
+impl From<u32> for CompressionType {
+    fn from(n: u32) -> Self {
+        match n {
+            1 => CompressionType::ELFCOMPRESS_ZLIB,
+            2 => CompressionType::ELFCOMPRESS_ZSTD,
+            x @ ELFCOMPRESS_LOOS..=ELFCOMPRESS_HIOS => CompressionType::OsSpecific(x),
+            x @ ELFCOMPRESS_LOPROC..=ELFCOMPRESS_HIPROC => CompressionType::ProcessorSpecific(x),
+            n => CompressionType::Unknown(n),
+        }
+    }
+}
+
+impl From<CompressionType> for u32 {
+    fn from(t: CompressionType) -> Self {
+        match t {
+            CompressionType::ELFCOMPRESS_ZLIB => 1,
+            CompressionType::ELFCOMPRESS_ZSTD => 2,
+            CompressionType::OsSpecific(n)
+            | CompressionType::ProcessorSpecific(n)
+            | CompressionType::Unknown(n) => n,
+        }
+    }
+}
+
+/// How a section is compressed: an `Elf32_Chdr`/`Elf64_Chdr` of a
+/// `SHF_COMPRESSED` section, or the `"ZLIB"` prefix of a `.zdebug_*`
+/// section.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CompressionHeader {
+    pub ch_type: CompressionType,
+    /// Size of the uncompressed data.
+    pub size: u64,
+    /// Alignment of the uncompressed data.
+    pub addralign: u64,
+    /// Size of the header preceding the compressed data.
+    pub header_size: usize,
+}
+
+impl CompressionHeader {
+    /// Parses the `Chdr` at the start of a `SHF_COMPRESSED` section.
+    pub(crate) fn parse(endian: ElfEndian, class: ElfClass, data: &[u8]) -> Option<Self> {
+        let ch_type = read_u32(endian, data, 0)?.into();
+        match class {
+            ElfClass::Elf64 => Some(Self {
+                ch_type,
+                size: read_u64(endian, data, 8)?,
+                addralign: read_u64(endian, data, 16)?,
+                header_size: 24,
+            }),
+            _ => Some(Self {
+                ch_type,
+                size: read_u32(endian, data, 4)? as u64,
+                addralign: read_u32(endian, data, 8)? as u64,
+                header_size: 12,
+            }),
+        }
+    }
+
+    /// Parses the `"ZLIB"` magic and big-endian size of a `.zdebug_*`
+    /// section.
+    pub(crate) fn parse_zdebug(data: &[u8], addralign: u64) -> Option<Self> {
+        if !data.starts_with(b"ZLIB") {
+            return None;
+        }
+        Some(Self {
+            ch_type: CompressionType::ELFCOMPRESS_ZLIB,
+            size: read_u64(ElfEndian::BigEndian, data, 4)?,
+            addralign,
+            header_size: 12,
+        })
+    }
+}
+
+/// Decompresses `data` into exactly `header.size` bytes.
+#[cfg(any(feature = "zlib", feature = "zstd"))]
+pub(crate) fn decompress(header: &CompressionHeader, data: &[u8]) -> Result<Vec<u8>, Error> {
+    let size = usize::try_from(header.size).map_err(|_| Error::DecompressionFailed)?;
+    let output = match header.ch_type {
+        #[cfg(feature = "zlib")]
+        CompressionType::ELFCOMPRESS_ZLIB => {
+            miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(data, size)
+                .map_err(|_| Error::DecompressionFailed)?
+        }
+        #[cfg(feature = "zstd")]
+        CompressionType::ELFCOMPRESS_ZSTD => {
+            use ruzstd::io::Read;
+
+            // Grow the output as it is decoded rather than trusting `size`
+            // up front, and read one byte past it to detect excess data.
+            let mut output = Vec::new();
+            ruzstd::decoding::StreamingDecoder::new(data)
+                .map_err(|_| Error::DecompressionFailed)?
+                .take(header.size.saturating_add(1))
+                .read_to_end(&mut output)
+                .map_err(|_| Error::DecompressionFailed)?;
+            output
+        }
+        t => return Err(Error::UnsupportedCompression(t.into())),
+    };
+    if output.len() != size {
+        return Err(Error::DecompressionFailed);
+    }
+    Ok(output)
+}
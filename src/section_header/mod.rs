@@ -1,33 +1,91 @@
+use crate::elf_header::ElfMachine;
+
+#[allow(clippy::module_inception)]
 mod section_header;
 
 pub use section_header::{SectionHeader32, SectionHeader64};
 
+mod compression;
+#[cfg(any(feature = "zlib", feature = "zstd"))]
+pub(crate) use compression::decompress;
+pub use compression::{CompressionHeader, CompressionType};
+
+/// `e_shstrndx` value marking that the real index of the section name string
+/// table is held in `sh_link` of section header 0.
+pub(crate) const SHN_XINDEX: u16 = 0xffff;
+
 const SHT_LOOS: u32 = 0x60000000;
 const SHT_HIOS: u32 = 0x6fffffff;
 const SHT_LOPROC: u32 = 0x70000000;
 const SHT_HIPROC: u32 = 0x7fffffff;
 const SHT_LOUSER: u32 = 0x80000000;
 const SHT_HIUSER: u32 = 0xffffffff;
+
+/// Section type. Processor-specific types are only named by
+/// `SectionType::new`, as their meaning depends on the target machine.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SectionType {
-    SHT_NULL,          // 0x0,
-    SHT_PROGBITS,      // 0x1,
-    SHT_SYMTAB,        // 0x2,
-    SHT_STRTAB,        // 0x3,
-    SHT_RELA,          // 0x4,
-    SHT_HASH,          // 0x5,
-    SHT_DYNAMIC,       // 0x6,
-    SHT_NOTE,          // 0x7,
-    SHT_NOBITS,        // 0x8,
-    SHT_REL,           // 0x9,
-    SHT_SHLIB,         // 0x0A,
-    SHT_DYNSYM,        // 0x0B,
-    SHT_INIT_ARRAY,    // 0x0E,
-    SHT_FINI_ARRAY,    // 0x0F,
-    SHT_PREINIT_ARRAY, // 0x10,
-    SHT_GROUP,         // 0x11,
-    SHT_SYMTAB_SHNDX,  // 0x12,
-    SHT_NUM,           // 0x13,
+    SHT_NULL,                     // 0x00,
+    SHT_PROGBITS,                 // 0x01,
+    SHT_SYMTAB,                   // 0x02,
+    SHT_STRTAB,                   // 0x03,
+    SHT_RELA,                     // 0x04,
+    SHT_HASH,                     // 0x05,
+    SHT_DYNAMIC,                  // 0x06,
+    SHT_NOTE,                     // 0x07,
+    SHT_NOBITS,                   // 0x08,
+    SHT_REL,                      // 0x09,
+    SHT_SHLIB,                    // 0x0A,
+    SHT_DYNSYM,                   // 0x0B,
+    SHT_INIT_ARRAY,               // 0x0E,
+    SHT_FINI_ARRAY,               // 0x0F,
+    SHT_PREINIT_ARRAY,            // 0x10,
+    SHT_GROUP,                    // 0x11,
+    SHT_SYMTAB_SHNDX,             // 0x12,
+    SHT_RELR,                     // 0x13,
+    SHT_ANDROID_REL,              // 0x60000001,
+    SHT_ANDROID_RELA,             // 0x60000002,
+    SHT_GNU_INCREMENTAL_INPUTS,   // 0x6FFF4700,
+    SHT_LLVM_ODRTAB,              // 0x6FFF4C00,
+    SHT_LLVM_LINKER_OPTIONS,      // 0x6FFF4C01,
+    SHT_LLVM_ADDRSIG,             // 0x6FFF4C03,
+    SHT_LLVM_DEPENDENT_LIBRARIES, // 0x6FFF4C04,
+    SHT_LLVM_SYMPART,             // 0x6FFF4C05,
+    SHT_LLVM_PART_EHDR,           // 0x6FFF4C06,
+    SHT_LLVM_PART_PHDR,           // 0x6FFF4C07,
+    SHT_LLVM_BB_ADDR_MAP_V0,      // 0x6FFF4C08,
+    SHT_LLVM_CALL_GRAPH_PROFILE,  // 0x6FFF4C09,
+    SHT_LLVM_BB_ADDR_MAP,         // 0x6FFF4C0A,
+    SHT_LLVM_OFFLOADING,          // 0x6FFF4C0B,
+    SHT_LLVM_LTO,                 // 0x6FFF4C0C,
+    SHT_ANDROID_RELR,             // 0x6FFFFF00,
+    SHT_GNU_ATTRIBUTES,           // 0x6FFFFFF5,
+    SHT_GNU_HASH,                 // 0x6FFFFFF6,
+    SHT_GNU_LIBLIST,              // 0x6FFFFFF7,
+    SHT_CHECKSUM,                 // 0x6FFFFFF8,
+    SHT_SUNW_move,                // 0x6FFFFFFA,
+    SHT_SUNW_COMDAT,              // 0x6FFFFFFB,
+    SHT_SUNW_syminfo,             // 0x6FFFFFFC,
+    SHT_GNU_verdef,               // 0x6FFFFFFD,
+    SHT_GNU_verneed,              // 0x6FFFFFFE,
+    SHT_GNU_versym,               // 0x6FFFFFFF,
+    SHT_ARM_EXIDX,                // 0x70000001,
+    SHT_ARM_PREEMPTMAP,           // 0x70000002,
+    SHT_ARM_ATTRIBUTES,           // 0x70000003,
+    SHT_ARM_DEBUGOVERLAY,         // 0x70000004,
+    SHT_ARM_OVERLAYSECTION,       // 0x70000005,
+    SHT_RISCV_ATTRIBUTES,         // 0x70000003,
+    SHT_X86_64_UNWIND,            // 0x70000001,
+    SHT_MIPS_REGINFO,             // 0x70000006,
+    SHT_MIPS_OPTIONS,             // 0x7000000D,
+    SHT_MIPS_DWARF,               // 0x7000001E,
+    SHT_MIPS_ABIFLAGS,            // 0x7000002A,
+    SHT_MSP430_ATTRIBUTES,        // 0x70000003,
+    SHT_IA_64_EXT,                // 0x70000000,
+    SHT_IA_64_UNWIND,             // 0x70000001,
+    SHT_PARISC_EXT,               // 0x70000000,
+    SHT_PARISC_UNWIND,            // 0x70000001,
+    SHT_PARISC_DOC,               // 0x70000002,
     OsSpecific(u32),
     ProcessorSpecific(u32),
     ApplicationSpecific(u32),
@@ -37,16 +95,16 @@ pub enum SectionType {
 impl From<u32> for SectionType {
     fn from(n: u32) -> Self {
         match n {
-            0x0 => SectionType::SHT_NULL,
-            0x1 => SectionType::SHT_PROGBITS,
-            0x2 => SectionType::SHT_SYMTAB,
-            0x3 => SectionType::SHT_STRTAB,
-            0x4 => SectionType::SHT_RELA,
-            0x5 => SectionType::SHT_HASH,
-            0x6 => SectionType::SHT_DYNAMIC,
-            0x7 => SectionType::SHT_NOTE,
-            0x8 => SectionType::SHT_NOBITS,
-            0x9 => SectionType::SHT_REL,
+            0x00 => SectionType::SHT_NULL,
+            0x01 => SectionType::SHT_PROGBITS,
+            0x02 => SectionType::SHT_SYMTAB,
+            0x03 => SectionType::SHT_STRTAB,
+            0x04 => SectionType::SHT_RELA,
+            0x05 => SectionType::SHT_HASH,
+            0x06 => SectionType::SHT_DYNAMIC,
+            0x07 => SectionType::SHT_NOTE,
+            0x08 => SectionType::SHT_NOBITS,
+            0x09 => SectionType::SHT_REL,
             0x0A => SectionType::SHT_SHLIB,
             0x0B => SectionType::SHT_DYNSYM,
             0x0E => SectionType::SHT_INIT_ARRAY,
@@ -54,7 +112,33 @@ impl From<u32> for SectionType {
             0x10 => SectionType::SHT_PREINIT_ARRAY,
             0x11 => SectionType::SHT_GROUP,
             0x12 => SectionType::SHT_SYMTAB_SHNDX,
-            0x13 => SectionType::SHT_NUM,
+            0x13 => SectionType::SHT_RELR,
+            0x60000001 => SectionType::SHT_ANDROID_REL,
+            0x60000002 => SectionType::SHT_ANDROID_RELA,
+            0x6FFF4700 => SectionType::SHT_GNU_INCREMENTAL_INPUTS,
+            0x6FFF4C00 => SectionType::SHT_LLVM_ODRTAB,
+            0x6FFF4C01 => SectionType::SHT_LLVM_LINKER_OPTIONS,
+            0x6FFF4C03 => SectionType::SHT_LLVM_ADDRSIG,
+            0x6FFF4C04 => SectionType::SHT_LLVM_DEPENDENT_LIBRARIES,
+            0x6FFF4C05 => SectionType::SHT_LLVM_SYMPART,
+            0x6FFF4C06 => SectionType::SHT_LLVM_PART_EHDR,
+            0x6FFF4C07 => SectionType::SHT_LLVM_PART_PHDR,
+            0x6FFF4C08 => SectionType::SHT_LLVM_BB_ADDR_MAP_V0,
+            0x6FFF4C09 => SectionType::SHT_LLVM_CALL_GRAPH_PROFILE,
+            0x6FFF4C0A => SectionType::SHT_LLVM_BB_ADDR_MAP,
+            0x6FFF4C0B => SectionType::SHT_LLVM_OFFLOADING,
+            0x6FFF4C0C => SectionType::SHT_LLVM_LTO,
+            0x6FFFFF00 => SectionType::SHT_ANDROID_RELR,
+            0x6FFFFFF5 => SectionType::SHT_GNU_ATTRIBUTES,
+            0x6FFFFFF6 => SectionType::SHT_GNU_HASH,
+            0x6FFFFFF7 => SectionType::SHT_GNU_LIBLIST,
+            0x6FFFFFF8 => SectionType::SHT_CHECKSUM,
+            0x6FFFFFFA => SectionType::SHT_SUNW_move,
+            0x6FFFFFFB => SectionType::SHT_SUNW_COMDAT,
+            0x6FFFFFFC => SectionType::SHT_SUNW_syminfo,
+            0x6FFFFFFD => SectionType::SHT_GNU_verdef,
+            0x6FFFFFFE => SectionType::SHT_GNU_verneed,
+            0x6FFFFFFF => SectionType::SHT_GNU_versym,
             x @ SHT_LOOS..=SHT_HIOS => SectionType::OsSpecific(x),
             x @ SHT_LOPROC..=SHT_HIPROC => SectionType::ProcessorSpecific(x),
             x @ SHT_LOUSER..=SHT_HIUSER => SectionType::ApplicationSpecific(x),
@@ -63,6 +147,38 @@ impl From<u32> for SectionType {
     }
 }
 
+impl SectionType {
+    /// Decodes `sh_type`, resolving processor-specific types for `machine`.
+    pub fn new(machine: ElfMachine, n: u32) -> Self {
+        match (machine, n) {
+            (ElfMachine::ARM, 0x70000001) => SectionType::SHT_ARM_EXIDX,
+            (ElfMachine::ARM, 0x70000002) => SectionType::SHT_ARM_PREEMPTMAP,
+            (ElfMachine::ARM, 0x70000003) => SectionType::SHT_ARM_ATTRIBUTES,
+            (ElfMachine::ARM, 0x70000004) => SectionType::SHT_ARM_DEBUGOVERLAY,
+            (ElfMachine::ARM, 0x70000005) => SectionType::SHT_ARM_OVERLAYSECTION,
+            (ElfMachine::RISC_V, 0x70000003) => SectionType::SHT_RISCV_ATTRIBUTES,
+            (ElfMachine::x86_64, 0x70000001) => SectionType::SHT_X86_64_UNWIND,
+            (ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE, 0x70000006) => {
+                SectionType::SHT_MIPS_REGINFO
+            }
+            (ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE, 0x7000000D) => {
+                SectionType::SHT_MIPS_OPTIONS
+            }
+            (ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE, 0x7000001E) => SectionType::SHT_MIPS_DWARF,
+            (ElfMachine::MIPS | ElfMachine::MIPS_RS3_LE, 0x7000002A) => {
+                SectionType::SHT_MIPS_ABIFLAGS
+            }
+            (ElfMachine::MSP430, 0x70000003) => SectionType::SHT_MSP430_ATTRIBUTES,
+            (ElfMachine::IA_64, 0x70000000) => SectionType::SHT_IA_64_EXT,
+            (ElfMachine::IA_64, 0x70000001) => SectionType::SHT_IA_64_UNWIND,
+            (ElfMachine::PARISC, 0x70000000) => SectionType::SHT_PARISC_EXT,
+            (ElfMachine::PARISC, 0x70000001) => SectionType::SHT_PARISC_UNWIND,
+            (ElfMachine::PARISC, 0x70000002) => SectionType::SHT_PARISC_DOC,
+            (_, n) => n.into(),
+        }
+    }
+}
+
 bitflags! {
     #[derive(Debug, Copy, Clone, PartialEq, Eq)]
     pub struct SectionHeaderFlags: u64 {
@@ -75,7 +191,9 @@ bitflags! {
         const SHF_LINK_ORDER        = 0x80;
         const SHF_OS_NONCONFORMING  = 0x100;
         const SHF_GROUP             = 0x200;
-        const SHF_TLS	            = 0x400;
+        const SHF_TLS               = 0x400;
+        const SHF_COMPRESSED        = 0x800;
+        const SHF_GNU_RETAIN        = 0x200000;
         const SHF_MASKOS            = 0x0ff00000;
         const SHF_MASKPROC          = 0xf0000000;
         const SHF_ORDERED           = 0x40000000;
@@ -83,10 +201,56 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// x86-64 processor-specific section flags.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct X86_64SectionFlags: u64 {
+        /// The section may be placed beyond 2 GiB.
+        const SHF_X86_64_LARGE      = 0x10000000;
+    }
+}
+
+bitflags! {
+    /// ARM processor-specific section flags.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct ArmSectionFlags: u64 {
+        /// The section holds only code, no data.
+        const SHF_ARM_PURECODE      = 0x20000000;
+    }
+}
+
+/// The `SHF_MASKPROC` bits of `sh_flags`, named for the target machine as
+/// the same bit means different things on different machines.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MachineSectionFlags {
+    X86_64(X86_64SectionFlags),
+    Arm(ArmSectionFlags),
+    Unknown(u64),
+}
+
+impl MachineSectionFlags {
+    pub fn new(machine: ElfMachine, flags: u64) -> Self {
+        let flags = flags & SectionHeaderFlags::SHF_MASKPROC.bits();
+        match machine {
+            ElfMachine::x86_64 => {
+                MachineSectionFlags::X86_64(X86_64SectionFlags::from_bits_retain(flags))
+            }
+            ElfMachine::ARM => MachineSectionFlags::Arm(ArmSectionFlags::from_bits_retain(flags)),
+            _ => MachineSectionFlags::Unknown(flags),
+        }
+    }
+}
+
 pub trait SectionHeaderRaw {
     fn name_off(&self) -> u32;
 
-    fn sh_type(&self) -> SectionType;
+    fn raw_type(&self) -> u32;
+
+    /// `sh_type` without processor-specific types, which need the target
+    /// machine. `SectionHeaderEntry::resolved_type` resolves those too.
+    fn sh_type(&self) -> SectionType {
+        self.raw_type().into()
+    }
 
     fn flags(&self) -> SectionHeaderFlags;
 
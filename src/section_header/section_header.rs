@@ -1,65 +1,64 @@
-use core::ptr::read_unaligned;
-use num_traits::PrimInt;
-
-use super::{SectionHeaderFlags, SectionHeaderRaw, SectionType};
+use super::{SectionHeaderFlags, SectionHeaderRaw};
+use crate::endian::{Endianness, Pod, Word, U32, U64};
 
 #[repr(C)]
 #[derive(Debug)]
-pub struct SectionHeaderGen<T: PrimInt> {
-    sh_name: u32,
-    sh_type: u32,
-    sh_flags: T,
-    sh_addr: T,
-    sh_offset: T,
-    sh_size: T,
-    sh_link: u32,
-    sh_info: u32,
-    sh_addralign: T,
-    sh_entsize: T,
+pub struct SectionHeaderGen<W: Word, E: Endianness> {
+    sh_name: U32<E>,
+    sh_type: U32<E>,
+    sh_flags: W,
+    sh_addr: W,
+    sh_offset: W,
+    sh_size: W,
+    sh_link: U32<E>,
+    sh_info: U32<E>,
+    sh_addralign: W,
+    sh_entsize: W,
 }
 
-impl<T: PrimInt + Into<u64>> SectionHeaderRaw for SectionHeaderGen<T> {
+unsafe impl<W: Word, E: Endianness> Pod for SectionHeaderGen<W, E> {}
+
+impl<W: Word, E: Endianness> SectionHeaderRaw for SectionHeaderGen<W, E> {
     fn name_off(&self) -> u32 {
-        unsafe { read_unaligned(&self.sh_name) }
+        self.sh_name.get()
     }
 
-    fn sh_type(&self) -> SectionType {
-        unsafe { read_unaligned(&self.sh_type).into() }
+    fn raw_type(&self) -> u32 {
+        self.sh_type.get()
     }
 
     fn flags(&self) -> SectionHeaderFlags {
-        let flags = unsafe { read_unaligned(&self.sh_flags).into() };
-        SectionHeaderFlags::from_bits_truncate(flags)
+        SectionHeaderFlags::from_bits_truncate(self.sh_flags.to_u64())
     }
 
     fn addr(&self) -> u64 {
-        unsafe { read_unaligned(&self.sh_addr).into() }
+        self.sh_addr.to_u64()
     }
 
     fn offset(&self) -> u64 {
-        unsafe { read_unaligned(&self.sh_offset).into() }
+        self.sh_offset.to_u64()
     }
 
     fn size(&self) -> u64 {
-        unsafe { read_unaligned(&self.sh_size).into() }
+        self.sh_size.to_u64()
     }
 
     fn link(&self) -> u32 {
-        unsafe { read_unaligned(&self.sh_link).into() }
+        self.sh_link.get()
     }
 
     fn info(&self) -> u32 {
-        unsafe { read_unaligned(&self.sh_info).into() }
+        self.sh_info.get()
     }
 
     fn addralign(&self) -> u64 {
-        unsafe { read_unaligned(&self.sh_addralign).into() }
+        self.sh_addralign.to_u64()
     }
 
     fn entsize(&self) -> u64 {
-        unsafe { read_unaligned(&self.sh_entsize).into() }
+        self.sh_entsize.to_u64()
     }
 }
 
-pub type SectionHeader32 = SectionHeaderGen<u32>;
-pub type SectionHeader64 = SectionHeaderGen<u64>;
+pub type SectionHeader32<E> = SectionHeaderGen<U32<E>, E>;
+pub type SectionHeader64<E> = SectionHeaderGen<U64<E>, E>;
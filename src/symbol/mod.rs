@@ -0,0 +1,150 @@
+mod symbol32;
+mod symbol64;
+
+pub use symbol32::Symbol32;
+pub use symbol64::Symbol64;
+
+const STB_LOOS: u8 = 10;
+const STB_HIOS: u8 = 12;
+const STB_LOPROC: u8 = 13;
+const STB_HIPROC: u8 = 15;
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymbolBinding {
+    STB_LOCAL,  // 0
+    STB_GLOBAL, // 1
+    STB_WEAK,   // 2
+    OsSpecific(u8),
+    ProcessorSpecific(u8),
+    Unknown(u8),
+}
+
+impl From<u8> for SymbolBinding {
+    fn from(n: u8) -> Self {
+        match n {
+            0 => SymbolBinding::STB_LOCAL,
+            1 => SymbolBinding::STB_GLOBAL,
+            2 => SymbolBinding::STB_WEAK,
+            x @ STB_LOOS..=STB_HIOS => SymbolBinding::OsSpecific(x),
+            x @ STB_LOPROC..=STB_HIPROC => SymbolBinding::ProcessorSpecific(x),
+            n => SymbolBinding::Unknown(n),
+        }
+    }
+}
+
+const STT_LOOS: u8 = 10;
+const STT_HIOS: u8 = 12;
+const STT_LOPROC: u8 = 13;
+const STT_HIPROC: u8 = 15;
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymbolType {
+    STT_NOTYPE,  // 0
+    STT_OBJECT,  // 1
+    STT_FUNC,    // 2
+    STT_SECTION, // 3
+    STT_FILE,    // 4
+    STT_COMMON,  // 5
+    STT_TLS,     // 6
+    OsSpecific(u8),
+    ProcessorSpecific(u8),
+    Unknown(u8),
+}
+
+impl From<u8> for SymbolType {
+    fn from(n: u8) -> Self {
+        match n {
+            0 => SymbolType::STT_NOTYPE,
+            1 => SymbolType::STT_OBJECT,
+            2 => SymbolType::STT_FUNC,
+            3 => SymbolType::STT_SECTION,
+            4 => SymbolType::STT_FILE,
+            5 => SymbolType::STT_COMMON,
+            6 => SymbolType::STT_TLS,
+            x @ STT_LOOS..=STT_HIOS => SymbolType::OsSpecific(x),
+            x @ STT_LOPROC..=STT_HIPROC => SymbolType::ProcessorSpecific(x),
+            n => SymbolType::Unknown(n),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymbolVisibility {
+    STV_DEFAULT,   // 0
+    STV_INTERNAL,  // 1
+    STV_HIDDEN,    // 2
+    STV_PROTECTED, // 3
+}
+
+impl From<u8> for SymbolVisibility {
+    fn from(n: u8) -> Self {
+        match n & 0x3 {
+            0 => SymbolVisibility::STV_DEFAULT,
+            1 => SymbolVisibility::STV_INTERNAL,
+            2 => SymbolVisibility::STV_HIDDEN,
+            _ => SymbolVisibility::STV_PROTECTED,
+        }
+    }
+}
+
+const SHN_LORESERVE: u16 = 0xff00;
+const SHN_LOPROC: u16 = 0xff00;
+const SHN_HIPROC: u16 = 0xff1f;
+const SHN_LOOS: u16 = 0xff20;
+const SHN_HIOS: u16 = 0xff3f;
+/// The section a symbol is defined relative to, with the reserved
+/// `SHN_*` indices decoded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SectionIndex {
+    SHN_UNDEF,  // 0x0000
+    SHN_ABS,    // 0xfff1
+    SHN_COMMON, // 0xfff2
+    SHN_XINDEX, // 0xffff
+    Index(u16),
+    OsSpecific(u16),
+    ProcessorSpecific(u16),
+    Reserved(u16),
+}
+
+impl From<u16> for SectionIndex {
+    fn from(n: u16) -> Self {
+        match n {
+            0x0000 => SectionIndex::SHN_UNDEF,
+            0xfff1 => SectionIndex::SHN_ABS,
+            0xfff2 => SectionIndex::SHN_COMMON,
+            0xffff => SectionIndex::SHN_XINDEX,
+            x @ SHN_LOPROC..=SHN_HIPROC => SectionIndex::ProcessorSpecific(x),
+            x @ SHN_LOOS..=SHN_HIOS => SectionIndex::OsSpecific(x),
+            x @ SHN_LORESERVE..=0xffff => SectionIndex::Reserved(x),
+            x => SectionIndex::Index(x),
+        }
+    }
+}
+
+pub trait SymbolRaw {
+    fn name_off(&self) -> u32;
+
+    fn value(&self) -> u64;
+
+    fn size(&self) -> u64;
+
+    fn info(&self) -> u8;
+
+    fn other(&self) -> u8;
+
+    fn shndx(&self) -> u16;
+
+    fn binding(&self) -> SymbolBinding {
+        (self.info() >> 4).into()
+    }
+
+    fn sym_type(&self) -> SymbolType {
+        (self.info() & 0xf).into()
+    }
+
+    fn visibility(&self) -> SymbolVisibility {
+        self.other().into()
+    }
+
+    fn section_index(&self) -> SectionIndex {
+        self.shndx().into()
+    }
+}
@@ -0,0 +1,41 @@
+use crate::endian::{Endianness, Pod, U16, U32, U64};
+use crate::symbol::SymbolRaw;
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct Symbol64<E: Endianness> {
+    st_name: U32<E>,
+    st_info: u8,
+    st_other: u8,
+    st_shndx: U16<E>,
+    st_value: U64<E>,
+    st_size: U64<E>,
+}
+
+unsafe impl<E: Endianness> Pod for Symbol64<E> {}
+
+impl<E: Endianness> SymbolRaw for Symbol64<E> {
+    fn name_off(&self) -> u32 {
+        self.st_name.get()
+    }
+
+    fn value(&self) -> u64 {
+        self.st_value.get()
+    }
+
+    fn size(&self) -> u64 {
+        self.st_size.get()
+    }
+
+    fn info(&self) -> u8 {
+        self.st_info
+    }
+
+    fn other(&self) -> u8 {
+        self.st_other
+    }
+
+    fn shndx(&self) -> u16 {
+        self.st_shndx.get()
+    }
+}
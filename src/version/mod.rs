@@ -0,0 +1,112 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+/// Version index of symbols local to the object.
+pub const VER_NDX_LOCAL: u16 = 0;
+/// Version index of unversioned global symbols.
+pub const VER_NDX_GLOBAL: u16 = 1;
+
+const VERSYM_HIDDEN: u16 = 0x8000;
+
+bitflags! {
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct VersionFlags: u16 {
+        /// The version definition of the object itself.
+        const VER_FLG_BASE = 0x1;
+        /// A weak version reference.
+        const VER_FLG_WEAK = 0x2;
+        const VER_FLG_INFO = 0x4;
+    }
+}
+
+/// An entry of the `.gnu.version` table.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VersionIndex(pub u16);
+
+impl VersionIndex {
+    /// The version index, without the hidden bit.
+    pub fn index(self) -> u16 {
+        self.0 & !VERSYM_HIDDEN
+    }
+
+    /// Hidden symbols only bind to references that name their version.
+    pub fn is_hidden(self) -> bool {
+        self.0 & VERSYM_HIDDEN != 0
+    }
+
+    pub fn is_local(self) -> bool {
+        self.index() == VER_NDX_LOCAL
+    }
+
+    pub fn is_global(self) -> bool {
+        self.index() == VER_NDX_GLOBAL
+    }
+}
+
+/// The version of a dynamic symbol.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SymbolVersion<'a> {
+    pub name: &'a [u8],
+    pub hidden: bool,
+    /// The library the version is required from, if the symbol is imported.
+    pub file: Option<&'a [u8]>,
+}
+
+impl<'a> fmt::Display for SymbolVersion<'a> {
+    /// Formats the version the way it is appended to a symbol name: `@@` for
+    /// default definitions, `@` otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = match (self.hidden, self.file) {
+            (false, None) => "@@",
+            _ => "@",
+        };
+        let name = core::str::from_utf8(self.name).unwrap_or("");
+        write!(f, "{}{}", separator, name)
+    }
+}
+
+/// Splits a version name such as `GLIBC_2.2.5` into its prefix and dotted
+/// number, or returns `None` if it has no number.
+fn split_version(name: &[u8]) -> Option<(&[u8], &[u8])> {
+    let start = name.iter().position(u8::is_ascii_digit)?;
+    Some(name.split_at(start))
+}
+
+/// Compares version names by their dotted numbers, so that `GLIBC_2.14`
+/// orders after `GLIBC_2.2.5`. Missing components count as zero, and names
+/// without a number order before all others.
+pub fn compare_versions(a: &[u8], b: &[u8]) -> Ordering {
+    fn components(number: &[u8]) -> impl Iterator<Item = u64> + '_ {
+        number.split(|&c| c == b'.').map(|part| {
+            part.iter()
+                .take_while(|c| c.is_ascii_digit())
+                .fold(0u64, |n, &c| {
+                    n.saturating_mul(10).saturating_add((c - b'0') as u64)
+                })
+        })
+    }
+
+    match (split_version(a), split_version(b)) {
+        (None, None) => a.cmp(b),
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some((a_prefix, a_number)), Some((b_prefix, b_number))) => {
+            let mut a_parts = components(a_number);
+            let mut b_parts = components(b_number);
+            loop {
+                match (a_parts.next(), b_parts.next()) {
+                    (None, None) => return a_prefix.cmp(b_prefix),
+                    (a, b) => match a.unwrap_or(0).cmp(&b.unwrap_or(0)) {
+                        Ordering::Equal => {}
+                        order => return order,
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Whether `name` carries a version number, unlike e.g. `GLIBC_PRIVATE`.
+pub(crate) fn is_numbered_version(name: &[u8]) -> bool {
+    split_version(name).is_some()
+}
@@ -1,4 +1,35 @@
 const TEST_ELF_FILE: &str = "tests/data/ls";
+/// A shared object with both `.hash` and `.gnu.hash`, built from
+/// `int counter; int plugin_init(void); int plugin_run(int);
+/// void plugin_fini(void);` with `-nostdlib -Wl,--hash-style=both -s`.
+const TEST_HASH_LIB: &str = "tests/data/libhash.so";
+/// A shared object defining `old_api@@VERS_1`, `new_api@@VERS_2`,
+/// `api@VERS_1` and `api@@VERS_2`, where `VERS_2` inherits from `VERS_1`.
+const TEST_VERSIONED_LIB: &str = "tests/data/libversioned.so";
+/// An object file with DWARF, and copies of it whose debug sections are
+/// compressed by `objcopy --compress-debug-sections=zlib`, `=zstd` and
+/// `=zlib-gnu`.
+const TEST_DEBUG_OBJ: &str = "tests/data/debug.o";
+const TEST_DEBUG_OBJ_ZLIB: &str = "tests/data/debug-zlib.o";
+const TEST_DEBUG_OBJ_ZSTD: &str = "tests/data/debug-zstd.o";
+const TEST_DEBUG_OBJ_ZLIB_GNU: &str = "tests/data/debug-zlib-gnu.o";
+/// A PIE built with `-O2 -g` from a `main` calling `sum_of_squares`, into
+/// which `square` is inlined, and a copy with zlib compressed debug sections.
+#[cfg(any(feature = "gimli", feature = "std"))]
+const TEST_DEBUGINFO: &str = "tests/data/debuginfo";
+#[cfg(feature = "gimli")]
+const TEST_DEBUGINFO_ZLIB: &str = "tests/data/debuginfo-zlib";
+/// `TEST_DEBUGINFO` without debug info and with a `.gnu_debuglink` to its
+/// debug file made by `objcopy --only-keep-debug`, to which a
+/// `.gnu_debugaltlink` naming `sup.debug` with the build-id of
+/// `TEST_DEBUGINFO` was added.
+const TEST_DEBUGLINK: &str = "tests/data/debuglink";
+const TEST_DEBUGLINK_DEBUG: &str = "tests/data/debuglink.debug";
+/// The same program built with `-gsplit-dwarf`, and its `.dwo` file.
+#[cfg(feature = "gimli")]
+const TEST_SPLIT: &str = "tests/data/split";
+#[cfg(feature = "gimli")]
+const TEST_SPLIT_DWO: &str = "tests/data/split.dwo";
 
 #[test]
 fn test_unaligned_buffer() {
@@ -7,7 +38,7 @@ fn test_unaligned_buffer() {
     use std::fs::File;
     use std::io::Read;
 
-    use elf_rs::{Elf, ElfFile};
+    use elf_rs::{Elf, ElfFile, LittleEndian};
 
     for offset in 0..64 {
         let mut elf_file = File::open(TEST_ELF_FILE).expect("failed to open file");
@@ -21,11 +52,1775 @@ fn test_unaligned_buffer() {
             .read_to_end(&mut elf_buf)
             .expect("failed to read file");
 
-        let elf = Elf::from_bytes(&mut elf_buf[offset..]).expect("fail to load elf file");
+        let elf = Elf::from_bytes(&elf_buf[offset..]).expect("fail to load elf file");
 
         println!("elf {:?}", elf);
         if let Elf::Elf64(e) = elf {
             println!("elf header {:?}", e.elf_header());
+
+            let phdrs = e.program_headers_raw::<LittleEndian>().unwrap();
+            let shdrs = e.section_headers_raw::<LittleEndian>().unwrap();
+            assert_eq!(phdrs.len(), e.program_header_iter().count());
+            assert_eq!(shdrs.len(), e.section_header_iter().count());
+            assert!(e.lookup_section(b".dynsym").is_some());
+        }
+    }
+}
+
+/// Builds a minimal ELF image with one `PT_LOAD` program header and a
+/// `.shstrtab` section, in the requested class and byte order.
+fn build_test_elf(is_64: bool, big_endian: bool) -> Vec<u8> {
+    fn put(buf: &mut Vec<u8>, big_endian: bool, n: u64, size: usize) {
+        let bytes = if big_endian {
+            n.to_be_bytes()[8 - size..].to_vec()
+        } else {
+            n.to_le_bytes()[..size].to_vec()
+        };
+        buf.extend_from_slice(&bytes);
+    }
+
+    let word = if is_64 { 8 } else { 4 };
+    let (ehsize, phentsize, shentsize) = if is_64 { (64, 56, 64) } else { (52, 32, 40) };
+    let shstrtab = b"\0.shstrtab\0";
+    let phoff = ehsize;
+    let shstrtab_off = phoff + phentsize;
+    // Keep the section header table naturally aligned.
+    let shoff = (shstrtab_off + shstrtab.len() as u64 + 7) & !7;
+
+    let mut buf = vec![0x7f, b'E', b'L', b'F'];
+    buf.push(if is_64 { 2 } else { 1 });
+    buf.push(if big_endian { 2 } else { 1 });
+    buf.push(1);
+    buf.extend_from_slice(&[0; 9]);
+    put(&mut buf, big_endian, 2, 2); // e_type: ET_EXEC
+    put(&mut buf, big_endian, 0x14, 2); // e_machine: PowerPC
+    put(&mut buf, big_endian, 1, 4); // e_version
+    put(&mut buf, big_endian, 0x1000_0100, word); // e_entry
+    put(&mut buf, big_endian, phoff, word);
+    put(&mut buf, big_endian, shoff, word);
+    put(&mut buf, big_endian, 0, 4); // e_flags
+    put(&mut buf, big_endian, ehsize, 2);
+    put(&mut buf, big_endian, phentsize, 2);
+    put(&mut buf, big_endian, 1, 2);
+    put(&mut buf, big_endian, shentsize, 2);
+    put(&mut buf, big_endian, 2, 2);
+    put(&mut buf, big_endian, 1, 2);
+
+    put(&mut buf, big_endian, 1, 4); // p_type: PT_LOAD
+    if is_64 {
+        put(&mut buf, big_endian, 5, 4); // p_flags: R + X
+    }
+    put(&mut buf, big_endian, 0, word); // p_offset
+    put(&mut buf, big_endian, 0x1000_0000, word); // p_vaddr
+    put(&mut buf, big_endian, 0x1000_0000, word); // p_paddr
+    put(&mut buf, big_endian, shoff, word); // p_filesz
+    put(&mut buf, big_endian, 0x2000, word); // p_memsz
+    if !is_64 {
+        put(&mut buf, big_endian, 5, 4); // p_flags: R + X
+    }
+    put(&mut buf, big_endian, 0x10000, word); // p_align
+
+    buf.extend_from_slice(shstrtab);
+    buf.resize(shoff as usize, 0);
+
+    buf.resize(buf.len() + shentsize as usize, 0);
+    put(&mut buf, big_endian, 1, 4); // sh_name
+    put(&mut buf, big_endian, 3, 4); // sh_type: SHT_STRTAB
+    put(&mut buf, big_endian, 0, word); // sh_flags
+    put(&mut buf, big_endian, 0, word); // sh_addr
+    put(&mut buf, big_endian, shstrtab_off, word);
+    put(&mut buf, big_endian, shstrtab.len() as u64, word);
+    put(&mut buf, big_endian, 0, 4); // sh_link
+    put(&mut buf, big_endian, 0, 4); // sh_info
+    put(&mut buf, big_endian, 1, word); // sh_addralign
+    put(&mut buf, big_endian, 0, word); // sh_entsize
+
+    buf
+}
+
+#[test]
+fn test_byte_order() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    for &is_64 in &[false, true] {
+        for &big_endian in &[false, true] {
+            let elf_buf = build_test_elf(is_64, big_endian);
+            let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+
+            let expected_endian = if big_endian {
+                ElfEndian::BigEndian
+            } else {
+                ElfEndian::LittleEndian
+            };
+            assert_eq!(elf.elf_header().endianness(), expected_endian);
+            assert_eq!(elf.elf_header().elftype(), ElfType::ET_EXEC);
+            assert_eq!(elf.elf_header().machine(), ElfMachine::PowerPC);
+            assert_eq!(elf.entry_point(), 0x1000_0100);
+
+            let phdrs = elf.program_header_iter().collect::<Vec<_>>();
+            assert_eq!(phdrs.len(), 1);
+            assert_eq!(phdrs[0].ph_type(), ProgramType::LOAD);
+            assert_eq!(
+                phdrs[0].flags(),
+                ProgramHeaderFlags::READ | ProgramHeaderFlags::EXECUTE
+            );
+            assert_eq!(phdrs[0].vaddr(), 0x1000_0000);
+            assert_eq!(phdrs[0].memsz(), 0x2000);
+            assert_eq!(phdrs[0].align(), 0x10000);
+
+            let shstrtab = elf.lookup_section(b".shstrtab").expect("no .shstrtab");
+            assert_eq!(shstrtab.sh_type(), SectionType::SHT_STRTAB);
+            assert_eq!(shstrtab.content(), Some(&b"\0.shstrtab\0"[..]));
+        }
+    }
+}
+
+#[test]
+fn test_raw_headers_require_matching_byte_order() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = build_test_elf(true, true);
+    let elf = Elf64::from_bytes(&elf_buf).expect("fail to load elf file");
+
+    assert!(elf.elf_header_raw::<LittleEndian>().is_none());
+    let header = elf.elf_header_raw::<BigEndian>().expect("no elf header");
+    assert_eq!(header.machine(), elf.elf_header().machine());
+    assert_eq!(header.program_header_offset(), 64);
+
+    assert!(elf.program_headers_raw::<LittleEndian>().is_none());
+    let phdrs = elf
+        .program_headers_raw::<BigEndian>()
+        .expect("no program headers");
+    assert_eq!(phdrs[0].filesz(), elf.elf_header().section_header_offset());
+    assert_eq!(
+        elf.section_headers_raw::<BigEndian>().map(|s| s.len()),
+        Some(2)
+    );
+}
+
+fn read_test_elf() -> Vec<u8> {
+    std::fs::read(TEST_ELF_FILE).expect("failed to read file")
+}
+
+#[test]
+fn test_dynamic_symbols() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+
+    assert!(elf.symbol_iter().is_none());
+    let symbols = elf
+        .dynamic_symbol_iter()
+        .expect("no .dynsym")
+        .collect::<Vec<_>>();
+    assert_eq!(symbols.len(), 149);
+
+    assert_eq!(symbols[0].symbol_name(), Some(&b""[..]));
+    assert_eq!(symbols[0].section_index(), SectionIndex::SHN_UNDEF);
+
+    assert_eq!(symbols[3].symbol_name(), Some(&b"getenv"[..]));
+    assert_eq!(symbols[3].sym_type(), SymbolType::STT_FUNC);
+    assert_eq!(symbols[3].binding(), SymbolBinding::STB_GLOBAL);
+
+    let ls_mode = &symbols[120];
+    assert_eq!(ls_mode.symbol_name(), Some(&b"ls_mode"[..]));
+    assert_eq!(ls_mode.value(), 0x2201e0);
+    assert_eq!(ls_mode.size(), 4);
+    assert_eq!(ls_mode.sym_type(), SymbolType::STT_OBJECT);
+    assert_eq!(ls_mode.visibility(), SymbolVisibility::STV_DEFAULT);
+    assert_eq!(ls_mode.section_index(), SectionIndex::Index(24));
+    let section = ls_mode.section().expect("no section");
+    assert_eq!(section.section_name(), Some(&b".data"[..]));
+
+    let mut iter = elf.dynamic_symbol_iter().unwrap();
+    assert_eq!(
+        iter.nth(124).and_then(|s| s.symbol_name()),
+        Some(&b"program_invocation_name"[..])
+    );
+    assert_eq!(
+        iter.next().map(|s| s.binding()),
+        Some(SymbolBinding::STB_GLOBAL)
+    );
+}
+
+#[test]
+fn test_symbol_table_layout() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    fn summary(elf: &Elf) -> Vec<(Vec<u8>, u64, Option<usize>)> {
+        elf.symbol_iter()
+            .expect("no .symtab")
+            .map(|s| {
+                let name = s.symbol_name().unwrap().to_vec();
+                (name, s.size(), s.section_header_index())
+            })
+            .collect()
+    }
+
+    let elf_buf = std::fs::read(TEST_DEBUG_OBJ).expect("failed to read file");
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let symbols = summary(&elf);
+    assert_eq!(symbols.len(), 11);
+    assert_eq!(symbols[10], (b"sum_of_squares".to_vec(), 38, Some(1)));
+    let shoff = elf.elf_header().section_header_offset() as usize;
+    let symtab = elf.section_header_nth(19).unwrap();
+    assert_eq!(symtab.sh_type(), SectionType::SHT_SYMTAB);
+    let offset = symtab.offset() as usize;
+
+    // Re-lay `.symtab` with 8 bytes of padding per symbol.
+    let mut padded = elf_buf.clone();
+    let new_offset = padded.len();
+    for i in 0..symbols.len() {
+        padded.extend_from_slice(&elf_buf[offset + i * 24..offset + (i + 1) * 24]);
+        padded.extend_from_slice(&[0xff; 8]);
+    }
+    let symtab = shoff + 19 * 64;
+    padded[symtab + 24..symtab + 32].copy_from_slice(&(new_offset as u64).to_le_bytes());
+    padded[symtab + 32..symtab + 40].copy_from_slice(&(11u64 * 32).to_le_bytes());
+    padded[symtab + 56..symtab + 64].copy_from_slice(&32u64.to_le_bytes());
+    let elf = Elf::from_bytes(&padded).expect("fail to load elf file");
+    assert_eq!(summary(&elf), symbols);
+
+    // Move `sum_of_squares` to an extended section index, held by
+    // `.note.GNU-stack` turned into the `SHT_SYMTAB_SHNDX` section.
+    let st_shndx = new_offset + 10 * 32 + 6;
+    padded[st_shndx..st_shndx + 2].copy_from_slice(&0xffffu16.to_le_bytes());
+    let elf = Elf::from_bytes(&padded).expect("fail to load elf file");
+    let symbol = elf.symbol_iter().unwrap().nth(10).unwrap();
+    assert_eq!(symbol.section_index(), SectionIndex::SHN_XINDEX);
+    assert_eq!(symbol.section_header_index(), None);
+
+    let shndx_offset = padded.len();
+    for i in 0..11u32 {
+        let index: u32 = if i == 10 { 1 } else { 0 };
+        padded.extend_from_slice(&index.to_le_bytes());
+    }
+    let shndx = shoff + 16 * 64;
+    padded[shndx + 4..shndx + 8].copy_from_slice(&0x12u32.to_le_bytes());
+    padded[shndx + 24..shndx + 32].copy_from_slice(&(shndx_offset as u64).to_le_bytes());
+    padded[shndx + 32..shndx + 40].copy_from_slice(&44u64.to_le_bytes());
+    padded[shndx + 40..shndx + 44].copy_from_slice(&19u32.to_le_bytes());
+    padded[shndx + 56..shndx + 64].copy_from_slice(&4u64.to_le_bytes());
+    let elf = Elf::from_bytes(&padded).expect("fail to load elf file");
+    assert_eq!(summary(&elf), symbols);
+    let symbol = elf.symbol_iter().unwrap().nth(10).unwrap();
+    assert_eq!(symbol.section_index(), SectionIndex::SHN_XINDEX);
+    assert_eq!(
+        symbol.section().and_then(|s| s.section_name()),
+        Some(&b".text"[..])
+    );
+}
+
+#[test]
+fn test_relocations() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+
+    let rela_dyn = elf.lookup_section(b".rela.dyn").expect("no .rela.dyn");
+    let relocs = rela_dyn
+        .relocation_iter()
+        .expect("not a relocation section")
+        .collect::<Vec<_>>();
+    assert_eq!(relocs.len(), 206);
+    assert_eq!(relocs[0].offset(), 0x21eff0);
+    assert_eq!(relocs[0].r_type(), 8);
+    assert_eq!(relocs[0].sym_index(), 0);
+    assert_eq!(relocs[0].addend(), Some(0x5950));
+    assert!(relocs[0].symbol().is_none());
+    assert_eq!(
+        relocs[0].relocation_type(),
+        RelocationType::X86_64(RelocationX86_64::R_X86_64_RELATIVE)
+    );
+    assert_eq!(relocs[0].relocation_type().to_string(), "R_X86_64_RELATIVE");
+
+    let rela_plt = elf.lookup_section(b".rela.plt").expect("no .rela.plt");
+    let getenv = rela_plt
+        .relocation_iter()
+        .expect("not a relocation section")
+        .nth(2)
+        .expect("too few relocations");
+    assert_eq!(getenv.offset(), 0x21fc60);
+    assert_eq!(getenv.r_type(), 7);
+    assert_eq!(getenv.relocation_type().to_string(), "R_X86_64_JUMP_SLOT");
+    assert_eq!(getenv.sym_index(), 3);
+    assert_eq!(
+        getenv.symbol().and_then(|s| s.symbol_name()),
+        Some(&b"getenv"[..])
+    );
+
+    assert!(elf
+        .lookup_section(b".dynsym")
+        .expect("no .dynsym")
+        .relocation_iter()
+        .is_none());
+}
+
+#[test]
+fn test_relocation_type_names() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let cases = [
+        (ElfMachine::x86, 1, "R_386_32"),
+        (ElfMachine::AArch64, 1026, "R_AARCH64_JUMP_SLOT"),
+        (ElfMachine::ARM, 23, "R_ARM_RELATIVE"),
+        (ElfMachine::RISC_V, 5, "R_RISCV_JUMP_SLOT"),
+        (ElfMachine::PowerPC64, 22, "R_PPC64_RELATIVE"),
+        (ElfMachine::MIPS, 2, "R_MIPS_32"),
+        (ElfMachine::MIPS_RS3_LE, 3, "R_MIPS_REL32"),
+        (ElfMachine::x86_64, 0x1234, "<unknown>: 0x1234"),
+        (ElfMachine::SPARC, 3, "<unknown>: 0x3"),
+    ];
+    for (machine, r_type, name) in cases {
+        assert_eq!(RelocationType::new(machine, r_type).to_string(), name);
+    }
+}
+
+#[test]
+fn test_dynamic_section() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+
+    let entries = elf
+        .dynamic_iter()
+        .expect("no dynamic section")
+        .collect::<Vec<_>>();
+    assert_eq!(entries.len(), 27);
+    assert_eq!(entries[0].tag(), DynamicTag::DT_NEEDED);
+    assert_eq!(entries[4].tag(), DynamicTag::DT_INIT_ARRAY);
+    assert_eq!(entries[4].value(), 0x21eff0);
+    assert_eq!(entries[8].tag(), DynamicTag::DT_GNU_HASH);
+    assert_eq!(entries[21].flags(), Some(DynamicFlags::DF_BIND_NOW));
+    assert_eq!(
+        entries[22].flags_1(),
+        Some(DynamicFlags1::DF_1_NOW | DynamicFlags1::DF_1_PIE)
+    );
+    assert_eq!(entries[26].tag(), DynamicTag::DT_RELACOUNT);
+    assert_eq!(entries[26].value(), 193);
+
+    let needed = elf.needed_libraries().collect::<Vec<_>>();
+    assert_eq!(needed, [&b"libselinux.so.1"[..], &b"libc.so.6"[..]]);
+    assert_eq!(elf.soname(), None);
+    assert_eq!(elf.runpath(), None);
+
+    let section = elf.lookup_section(b".dynamic").expect("no .dynamic");
+    let from_section = section.dynamic_iter().expect("not a dynamic section");
+    assert_eq!(from_section.count(), entries.len());
+    assert_eq!(
+        section
+            .dynamic_iter()
+            .and_then(|mut d| d.next())
+            .and_then(|d| d.string_value()),
+        Some(&b"libselinux.so.1"[..])
+    );
+
+    // A `PT_DYNAMIC` without its `DT_NULL` terminator still resolves strings.
+    let mut elf_buf = elf_buf.clone();
+    let filesz_offset = {
+        let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+        let index = elf
+            .program_header_iter()
+            .position(|p| p.ph_type() == ProgramType::DYNAMIC)
+            .unwrap();
+        elf.elf_header().program_header_offset() as usize + index * 56 + 32
+    };
+    elf_buf[filesz_offset..filesz_offset + 8].copy_from_slice(&(27u64 * 16).to_le_bytes());
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    assert_eq!(elf.dynamic_iter().unwrap().count(), 27);
+    assert!(elf.dynamic_iter().unwrap().string_table().is_some());
+    assert_eq!(elf.needed_libraries().count(), 2);
+}
+
+#[test]
+fn test_vaddr_translation() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+
+    assert_eq!(elf.vaddr_to_offset(0x3e90), Some(0x3e90));
+    assert_eq!(elf.vaddr_to_offset(0x21eff0), Some(0x1eff0));
+    // .bss lives in the zero-filled tail of the second PT_LOAD segment.
+    assert_eq!(elf.vaddr_to_offset(0x220280), None);
+    assert_eq!(elf.vaddr_to_offset(0x300000), None);
+
+    assert_eq!(elf.offset_to_vaddr(0x1eff8), Some(0x21eff8));
+    assert_eq!(elf.offset_to_vaddr(0x1e700), None);
+
+    assert_eq!(
+        elf.read_at_vaddr(0x21eff0, 8),
+        Some(&[0x50, 0x59, 0, 0, 0, 0, 0, 0][..])
+    );
+    assert_eq!(elf.read_at_vaddr(0x220260, 16), None);
+
+    let mut buf = [0xff; 16];
+    assert_eq!(elf.read_at_vaddr_into(0x220260, &mut buf), Some(()));
+    assert_eq!(
+        buf,
+        [0xa0, 0x53, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(elf.read_at_vaddr_into(0x22155c, &mut buf[..8]), None);
+}
+
+#[test]
+fn test_notes() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+
+    let abi_tag = elf
+        .lookup_section(b".note.ABI-tag")
+        .and_then(|s| s.note_iter())
+        .and_then(|mut n| n.next())
+        .expect("no ABI tag note");
+    assert_eq!(abi_tag.name(), b"GNU");
+    match abi_tag.gnu_note() {
+        Some(GnuNote::AbiTag(tag)) => assert_eq!(
+            tag,
+            GnuAbiTag {
+                os: GnuAbiOs::ELF_NOTE_OS_LINUX,
+                major: 3,
+                minor: 2,
+                subminor: 0,
+            }
+        ),
+        n => panic!("unexpected note {:?}", n),
+    }
+
+    let build_id = [
+        0x95, 0x67, 0xf9, 0xa2, 0x8e, 0x66, 0xf4, 0xd7, 0xec, 0x4b, 0xaf, 0x31, 0xcf, 0xbf, 0x68,
+        0xd0, 0x41, 0x0f, 0x0a, 0xe6,
+    ];
+    let segment = elf
+        .program_header_iter()
+        .find(|p| p.ph_type() == ProgramType::NOTE)
+        .expect("no PT_NOTE segment");
+    let notes = segment.note_iter().expect("not a note segment");
+    let types = notes.map(|n| n.n_type()).collect::<Vec<_>>();
+    assert_eq!(types, [1, 3]);
+    match segment.note_iter().and_then(|n| n.last()) {
+        Some(n) => match n.gnu_note() {
+            Some(GnuNote::BuildId(id)) => assert_eq!(id, build_id),
+            n => panic!("unexpected note {:?}", n),
+        },
+        None => panic!("no build-id note"),
+    }
+}
+
+#[test]
+fn test_gnu_property_note() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    // An 8-byte aligned NT_GNU_PROPERTY_TYPE_0 note with
+    // GNU_PROPERTY_X86_FEATURE_1_AND (IBT | SHSTK) followed by a gold
+    // version note.
+    let mut data = Vec::new();
+    for word in [4u32, 16, 5] {
+        data.extend_from_slice(&word.to_le_bytes());
+    }
+    data.extend_from_slice(b"GNU\0");
+    for word in [0xc0000002u32, 4, 3, 0] {
+        data.extend_from_slice(&word.to_le_bytes());
+    }
+    for word in [4u32, 9, 4] {
+        data.extend_from_slice(&word.to_le_bytes());
+    }
+    data.extend_from_slice(b"GNU\0");
+    data.extend_from_slice(b"gold 1.16\0\0\0\0\0\0\0");
+
+    let notes =
+        NoteIter::new(ElfEndian::LittleEndian, ElfClass::Elf64, &data, 8).collect::<Vec<_>>();
+    assert_eq!(notes.len(), 2);
+
+    match notes[0].gnu_note() {
+        Some(GnuNote::Property(props)) => {
+            let props = props.collect::<Vec<_>>();
+            assert_eq!(props.len(), 1);
+            assert_eq!(
+                props[0].pr_type(),
+                GnuPropertyType::ProcessorSpecific(0xc0000002)
+            );
+            assert_eq!(props[0].value_u32(), Some(3));
+        }
+        n => panic!("unexpected note {:?}", n),
+    }
+    match notes[1].gnu_note() {
+        Some(GnuNote::GoldVersion(v)) => assert_eq!(v, b"gold 1.16"),
+        n => panic!("unexpected note {:?}", n),
+    }
+}
+
+#[test]
+fn test_build_id() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+
+    let build_id = BuildId::new(elf.build_id().expect("no build-id"));
+    assert_eq!(
+        build_id.to_string(),
+        "9567f9a28e66f4d7ec4baf31cfbf68d0410f0ae6"
+    );
+    assert_eq!(
+        build_id.debug_path().map(|p| p.to_string()).as_deref(),
+        Some(".build-id/95/67f9a28e66f4d7ec4baf31cfbf68d0410f0ae6.debug")
+    );
+    assert!(BuildId::new(&[0x95]).debug_path().is_none());
+
+    // Without section headers the build-id is found through PT_NOTE.
+    let mut stripped = elf_buf.clone();
+    stripped[0x3c..0x3e].copy_from_slice(&[0, 0]);
+    let elf = Elf::from_bytes(&stripped).expect("fail to load elf file");
+    assert!(elf.lookup_section(b".note.gnu.build-id").is_none());
+    assert_eq!(elf.build_id(), Some(build_id.as_bytes()));
+}
+
+#[test]
+fn test_extended_numbering() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let mut elf_buf = build_test_elf(true, false);
+    let shoff = {
+        let elf = Elf64::from_bytes(&elf_buf).expect("fail to load elf file");
+        elf.elf_header().section_header_offset() as usize
+    };
+    // Move e_phnum, e_shnum and e_shstrndx into section header 0.
+    elf_buf[0x38..0x3a].copy_from_slice(&0xffffu16.to_le_bytes());
+    elf_buf[0x3c..0x3e].copy_from_slice(&0u16.to_le_bytes());
+    elf_buf[0x3e..0x40].copy_from_slice(&0xffffu16.to_le_bytes());
+    elf_buf[shoff + 32..shoff + 40].copy_from_slice(&2u64.to_le_bytes());
+    elf_buf[shoff + 40..shoff + 44].copy_from_slice(&1u32.to_le_bytes());
+    elf_buf[shoff + 44..shoff + 48].copy_from_slice(&1u32.to_le_bytes());
+
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    assert_eq!(elf.program_header_iter().count(), 1);
+    assert_eq!(elf.section_header_iter().count(), 2);
+    assert_eq!(
+        elf.shstr_section().and_then(|s| s.section_name()),
+        Some(&b".shstrtab"[..])
+    );
+    assert!(elf.lookup_section(b".shstrtab").is_some());
+
+    // Entry sizes are checked against the counts in section header 0.
+    let mut short = elf_buf.clone();
+    short[0x36..0x38].copy_from_slice(&0u16.to_le_bytes());
+    assert!(matches!(
+        Elf::from_bytes(&short),
+        Err(Error::InvalidProgramHeaderEntrySize(0))
+    ));
+    short[shoff + 44..shoff + 48].copy_from_slice(&0u32.to_le_bytes());
+    let elf = Elf::from_bytes(&short).expect("fail to load elf file");
+    assert_eq!(elf.program_header_iter().count(), 0);
+
+    let mut short = elf_buf;
+    short[0x3a..0x3c].copy_from_slice(&0u16.to_le_bytes());
+    assert!(matches!(
+        Elf::from_bytes(&short),
+        Err(Error::InvalidSectionHeaderEntrySize(0))
+    ));
+    short[shoff + 32..shoff + 40].copy_from_slice(&0u64.to_le_bytes());
+    let elf = Elf::from_bytes(&short).expect("fail to load elf file");
+    assert_eq!(elf.section_header_iter().count(), 0);
+}
+
+#[test]
+fn test_header_entry_size() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf64::from_bytes(&elf_buf).expect("fail to load elf file");
+    let names: Vec<_> = elf
+        .section_header_iter()
+        .map(|s| s.section_name().map(|n| n.to_vec()))
+        .collect();
+    let shoff = elf.elf_header().section_header_offset() as usize;
+
+    // Re-lay the section header table with 16 bytes of padding per entry.
+    let mut padded = elf_buf.clone();
+    padded.resize((padded.len() + 7) & !7, 0);
+    let new_shoff = padded.len();
+    for i in 0..names.len() {
+        let entry = &elf_buf[shoff + i * 64..shoff + (i + 1) * 64];
+        padded.extend_from_slice(entry);
+        padded.extend_from_slice(&[0xff; 16]);
+    }
+    padded[0x28..0x30].copy_from_slice(&(new_shoff as u64).to_le_bytes());
+    padded[0x3a..0x3c].copy_from_slice(&80u16.to_le_bytes());
+
+    let elf = Elf64::from_bytes(&padded).expect("fail to load elf file");
+    let padded_names: Vec<_> = elf
+        .section_header_iter()
+        .map(|s| s.section_name().map(|n| n.to_vec()))
+        .collect();
+    assert_eq!(padded_names, names);
+    assert!(elf.section_headers_raw::<LittleEndian>().is_none());
+    assert!(elf.program_headers_raw::<LittleEndian>().is_some());
+
+    // Entries smaller than the structure are rejected.
+    let mut short = elf_buf.clone();
+    short[0x36..0x38].copy_from_slice(&48u16.to_le_bytes());
+    assert!(matches!(
+        Elf64::from_bytes(&short),
+        Err(Error::InvalidProgramHeaderEntrySize(48))
+    ));
+
+    let mut short = elf_buf;
+    short[0x3a..0x3c].copy_from_slice(&40u16.to_le_bytes());
+    assert!(matches!(
+        Elf::from_bytes(&short),
+        Err(Error::InvalidSectionHeaderEntrySize(40))
+    ));
+}
+
+#[test]
+fn test_error_context() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = build_test_elf(true, false);
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    assert_eq!(
+        elf.try_section_header_nth(2).err(),
+        Some(Error::SectionHeaderIndexOutOfRange { index: 2, num: 2 })
+    );
+    assert_eq!(
+        elf.try_program_header_nth(1).err(),
+        Some(Error::ProgramHeaderIndexOutOfRange { index: 1, num: 1 })
+    );
+    assert_eq!(
+        elf.try_section_header_nth(1)
+            .and_then(|s| s.try_section_name()),
+        Ok(&b".shstrtab"[..])
+    );
+    let shoff = elf.elf_header().section_header_offset() as usize;
+
+    // Point the name of section 1 past the end of `.shstrtab`.
+    let mut bad_name = elf_buf.clone();
+    bad_name[shoff + 64..shoff + 68].copy_from_slice(&0x40u32.to_le_bytes());
+    let elf = Elf::from_bytes(&bad_name).expect("fail to load elf file");
+    let err = elf
+        .try_section_header_nth(1)
+        .and_then(|s| s.try_section_name())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidStringOffset {
+            offset: 0x40,
+            size: 11
+        }
+    );
+    assert_eq!(
+        format!("{}", err),
+        "string offset 0x40 is outside a 0xb byte string table"
+    );
+
+    // Grow the segment's p_filesz past the end of the buffer.
+    let mut bad_segment = elf_buf.clone();
+    bad_segment[0x40 + 32..0x40 + 40].copy_from_slice(&0x10_0000u64.to_le_bytes());
+    let elf = Elf::from_bytes(&bad_segment).expect("fail to load elf file");
+    assert_eq!(
+        elf.try_program_header_nth(0).and_then(|p| p.try_content()),
+        Err(Error::SegmentOutOfBounds {
+            offset: 0,
+            size: 0x10_0000
+        })
+    );
+
+    // Truncate the section header table.
+    let truncated = &elf_buf[..shoff + 100];
+    let elf = Elf::from_bytes(truncated).expect("fail to load elf file");
+    assert_eq!(
+        elf.try_section_header_nth(0).err(),
+        Some(Error::SectionHeaderTableOutOfBounds {
+            offset: shoff as u64,
+            size: 128
+        })
+    );
+
+    let mut bad_version = elf_buf.clone();
+    bad_version[6] = 2;
+    let elf = Elf::from_bytes(&bad_version).expect("fail to load elf file");
+    assert_eq!(
+        elf.validate(ValidationPolicy::Standard).err(),
+        Some(Error::ValidationFailed(Finding {
+            severity: Severity::Error,
+            kind: FindingKind::UnsupportedHeaderVersion(2),
+        }))
+    );
+    assert_eq!(Elf32::from_bytes(&elf_buf).err(), Some(Error::InvalidClass));
+}
+
+#[test]
+fn test_validate() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let report = elf
+        .validate(ValidationPolicy::Strict)
+        .expect("fail to validate elf file");
+    assert!(report.is_clean(), "{:?}", report);
+
+    for &is_64 in &[false, true] {
+        for &big_endian in &[false, true] {
+            let elf_buf = build_test_elf(is_64, big_endian);
+            let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+            let report = elf.validate(ValidationPolicy::Strict);
+            assert!(report.map(|r| r.is_clean()) == Ok(true));
+        }
+    }
+
+    let mut elf_buf = build_test_elf(true, false);
+    let shoff = {
+        let elf = Elf64::from_bytes(&elf_buf).expect("fail to load elf file");
+        elf.elf_header().section_header_offset() as usize
+    };
+    // Misalign p_vaddr of the PT_LOAD segment.
+    elf_buf[0x40 + 16..0x40 + 24].copy_from_slice(&0x1000_0010u64.to_le_bytes());
+    // Drop the NUL terminator of `.shstrtab` and point it at the ELF header.
+    elf_buf[shoff + 64 + 24..shoff + 64 + 32].copy_from_slice(&0u64.to_le_bytes());
+    elf_buf[shoff + 64 + 32..shoff + 64 + 40].copy_from_slice(&4u64.to_le_bytes());
+
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let report = elf
+        .validate(ValidationPolicy::Lenient)
+        .expect("fail to validate elf file");
+    let findings: Vec<_> = report.iter().cloned().collect();
+    assert_eq!(
+        findings,
+        vec![
+            Finding {
+                severity: Severity::Error,
+                kind: FindingKind::SegmentMisaligned(0),
+            },
+            Finding {
+                severity: Severity::Error,
+                kind: FindingKind::SectionOverlapsHeaders(1),
+            },
+            Finding {
+                severity: Severity::Warning,
+                kind: FindingKind::StringTableNotTerminated(1),
+            },
+        ]
+    );
+    assert_eq!(report.max_severity(), Some(Severity::Error));
+    assert!(report.is_acceptable(ValidationPolicy::Lenient));
+    assert!(!report.is_acceptable(ValidationPolicy::Standard));
+    assert_eq!(
+        elf.validate(ValidationPolicy::Standard).err(),
+        Some(Error::ValidationFailed(findings[0]))
+    );
+
+    // Only the warning is rejected once the errors are fixed.
+    let good_buf = build_test_elf(true, false);
+    let vaddr = 0x40 + 16..0x40 + 24;
+    elf_buf[vaddr.clone()].copy_from_slice(&good_buf[vaddr]);
+    let offset = shoff + 64 + 24..shoff + 64 + 32;
+    elf_buf[offset.clone()].copy_from_slice(&good_buf[offset]);
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    assert!(elf.validate(ValidationPolicy::Standard).is_ok());
+    assert_eq!(
+        elf.validate(ValidationPolicy::Strict).err(),
+        Some(Error::ValidationFailed(Finding {
+            severity: Severity::Warning,
+            kind: FindingKind::StringTableNotTerminated(1),
+        }))
+    );
+    let report = elf.validation_report();
+    assert_eq!(
+        report.rejected(ValidationPolicy::Strict),
+        Some(Finding {
+            severity: Severity::Warning,
+            kind: FindingKind::StringTableNotTerminated(1),
+        })
+    );
+}
+
+#[test]
+fn test_machine_registry() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let machine = ElfMachine::from(258);
+    assert_eq!(machine, ElfMachine::LoongArch);
+    assert_eq!(machine.name(), Some("EM_LOONGARCH"));
+    assert_eq!(machine.description(), Some("LoongArch"));
+    assert_eq!(machine.default_endian(), Some(ElfEndian::LittleEndian));
+    assert_eq!(machine.pointer_width(), None);
+
+    assert_eq!(ElfMachine::from(0x3E).name(), Some("EM_X86_64"));
+    assert_eq!(ElfMachine::x86_64.pointer_width(), Some(64));
+    assert_eq!(ElfMachine::from(247), ElfMachine::BPF);
+    assert_eq!(ElfMachine::from(164), ElfMachine::Hexagon);
+    assert_eq!(ElfMachine::from(0x9026), ElfMachine::Alpha);
+    assert_eq!(
+        ElfMachine::SPARC.default_endian(),
+        Some(ElfEndian::BigEndian)
+    );
+
+    for n in 0..=u16::MAX {
+        let machine = ElfMachine::from(n);
+        assert_eq!(u16::from(machine), n);
+        assert_eq!(
+            machine.name().is_none(),
+            machine == ElfMachine::MachineUnknown(n)
+        );
+    }
+    assert_eq!(ElfMachine::from(0x1234).name(), None);
+}
+
+#[test]
+fn test_machine_flags() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let cases: &[(u16, u32, &str)] = &[
+        (40, 0x5000400, "0x5000400, Version5 EABI, hard-float ABI"),
+        (40, 0x5000200, "0x5000200, Version5 EABI, soft-float ABI"),
+        (40, 0x4800000, "0x4800000, Version4 EABI, BE8"),
+        (243, 0x5, "0x5, RVC, double-float ABI"),
+        (243, 0x1d, "0x1d, RVC, RVE, TSO, double-float ABI"),
+        (243, 0x0, "0x0"),
+        (
+            8,
+            0x70001007,
+            "0x70001007, noreorder, pic, cpic, o32, mips32r2",
+        ),
+        (
+            8,
+            0x60000421,
+            "0x60000421, noreorder, abi2, nan2008, mips64",
+        ),
+        (21, 0x2, "0x2, abiv2"),
+        (83, 0x85, "0x85, avr:5, link-relax"),
+        (258, 0x43, "0x43, DOUBLE-FLOAT, OBJ-v1"),
+        (62, 0x1, "0x1"),
+    ];
+    let elf_buf = read_test_elf();
+    for &(machine, flags, expected) in cases {
+        let mut elf_buf = elf_buf.clone();
+        elf_buf[0x12..0x14].copy_from_slice(&machine.to_le_bytes());
+        elf_buf[0x30..0x34].copy_from_slice(&flags.to_le_bytes());
+        let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+        let machine_flags = elf.elf_header().machine_flags();
+        assert_eq!(format!("{}", machine_flags), expected);
+    }
+
+    let riscv = RiscVFlags(0x5);
+    assert!(riscv.rvc());
+    assert_eq!(riscv.float_abi(), RiscVFloatAbi::Double);
+    assert_eq!(RiscVFlags(0x1).float_abi(), RiscVFloatAbi::Soft);
+
+    let arm = ArmFlags(0x5000400);
+    assert_eq!(arm.eabi_version(), Some(5));
+    assert_eq!(arm.float_abi(), Some(ArmFloatAbi::Hard));
+
+    let mips = MipsFlags(0x60000421);
+    assert_eq!(mips.arch(), MipsArch::Mips64);
+    assert_eq!(mips.abi(), Some(MipsAbi::N32));
+    assert!(mips.nan2008() && !mips.pic());
+
+    assert_eq!(Ppc64Flags(2).abi(), Some(Ppc64Abi::ElfV2));
+    assert_eq!(
+        LoongArchFlags(0x43).float_abi(),
+        Some(LoongArchFloatAbi::Double)
+    );
+    assert_eq!(AvrFlags(0x85).arch(), 5);
+}
+
+#[test]
+fn test_program_types() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let types: Vec<_> = elf
+        .program_header_iter()
+        .map(|p| p.resolved_type())
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            ProgramType::PHDR,
+            ProgramType::INTERP,
+            ProgramType::LOAD,
+            ProgramType::LOAD,
+            ProgramType::DYNAMIC,
+            ProgramType::NOTE,
+            ProgramType::GNU_EH_FRAME,
+            ProgramType::GNU_STACK,
+            ProgramType::GNU_RELRO,
+        ]
+    );
+
+    // The same processor-specific value differs between machines.
+    assert_eq!(
+        ProgramType::new(ElfMachine::ARM, 0x70000001),
+        ProgramType::ARM_EXIDX
+    );
+    assert_eq!(
+        ProgramType::new(ElfMachine::IA_64, 0x70000001),
+        ProgramType::IA_64_UNWIND
+    );
+    assert_eq!(
+        ProgramType::new(ElfMachine::MIPS, 0x70000003),
+        ProgramType::MIPS_ABIFLAGS
+    );
+    assert_eq!(
+        ProgramType::new(ElfMachine::RISC_V, 0x70000003),
+        ProgramType::RISCV_ATTRIBUTES
+    );
+    assert_eq!(
+        ProgramType::new(ElfMachine::AArch64, 0x70000002),
+        ProgramType::AARCH64_MEMTAG_MTE
+    );
+    assert_eq!(
+        ProgramType::new(ElfMachine::x86_64, 0x70000001),
+        ProgramType::ProcessorSpecific(0x70000001)
+    );
+    assert_eq!(
+        ProgramType::from(0x65a3dbe6),
+        ProgramType::OPENBSD_RANDOMIZE
+    );
+
+    // Processor types resolve through the program header entry.
+    let mut elf_buf = build_test_elf(true, false);
+    elf_buf[0x12..0x14].copy_from_slice(&40u16.to_le_bytes());
+    elf_buf[0x40..0x44].copy_from_slice(&0x70000001u32.to_le_bytes());
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let ph = elf.program_header_nth(0).unwrap();
+    assert_eq!(ph.resolved_type(), ProgramType::ARM_EXIDX);
+    assert_eq!(ph.ph_type(), ProgramType::ProcessorSpecific(0x70000001));
+    assert_eq!(ph.raw_type(), 0x70000001);
+}
+
+#[test]
+fn test_section_types() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let types: Vec<_> = elf
+        .section_header_iter()
+        .skip(4)
+        .take(5)
+        .map(|s| s.resolved_type())
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            SectionType::SHT_GNU_HASH,
+            SectionType::SHT_DYNSYM,
+            SectionType::SHT_STRTAB,
+            SectionType::SHT_GNU_versym,
+            SectionType::SHT_GNU_verneed,
+        ]
+    );
+
+    assert_eq!(SectionType::from(0x13), SectionType::SHT_RELR);
+    assert_eq!(SectionType::from(0x6fff4c03), SectionType::SHT_LLVM_ADDRSIG);
+    assert_eq!(
+        SectionType::from(0x6fff4c09),
+        SectionType::SHT_LLVM_CALL_GRAPH_PROFILE
+    );
+    assert_eq!(
+        SectionType::new(ElfMachine::ARM, 0x70000003),
+        SectionType::SHT_ARM_ATTRIBUTES
+    );
+    assert_eq!(
+        SectionType::new(ElfMachine::RISC_V, 0x70000003),
+        SectionType::SHT_RISCV_ATTRIBUTES
+    );
+    assert_eq!(
+        SectionType::new(ElfMachine::x86_64, 0x70000001),
+        SectionType::SHT_X86_64_UNWIND
+    );
+    assert_eq!(
+        SectionType::new(ElfMachine::PowerPC, 0x70000001),
+        SectionType::ProcessorSpecific(0x70000001)
+    );
+
+    // Processor types resolve through the section header entry.
+    let mut elf_buf = build_test_elf(true, false);
+    elf_buf[0x12..0x14].copy_from_slice(&40u16.to_le_bytes());
+    elf_buf[204..208].copy_from_slice(&0x70000001u32.to_le_bytes());
+    elf_buf[208..216].copy_from_slice(&0x20000802u64.to_le_bytes());
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let sh = elf.section_header_nth(1).unwrap();
+    assert_eq!(sh.resolved_type(), SectionType::SHT_ARM_EXIDX);
+    assert_eq!(sh.sh_type(), SectionType::ProcessorSpecific(0x70000001));
+    assert_eq!(sh.raw_type(), 0x70000001);
+    assert_eq!(sh.flags().bits(), 0x20000802);
+    assert_eq!(
+        sh.machine_flags(),
+        MachineSectionFlags::Arm(ArmSectionFlags::SHF_ARM_PURECODE)
+    );
+
+    // The same processor-specific bit differs between machines.
+    assert_eq!(
+        MachineSectionFlags::new(ElfMachine::x86_64, 0x10000003),
+        MachineSectionFlags::X86_64(X86_64SectionFlags::SHF_X86_64_LARGE)
+    );
+    assert_eq!(
+        MachineSectionFlags::new(ElfMachine::ARM, 0x10000000),
+        MachineSectionFlags::Arm(ArmSectionFlags::from_bits_retain(0x10000000))
+    );
+    assert_eq!(
+        MachineSectionFlags::new(ElfMachine::PowerPC, 0x20000000),
+        MachineSectionFlags::Unknown(0x20000000)
+    );
+}
+
+#[test]
+fn test_string_table() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let strtab = StringTable::new(b"\0foo\0bar\0baz");
+    assert_eq!(strtab.get(0), Ok(&b""[..]));
+    assert_eq!(strtab.get(1), Ok(&b"foo"[..]));
+    assert_eq!(strtab.get(6), Ok(&b"ar"[..]));
+    assert_eq!(strtab.get_cstr(5).map(|s| s.to_bytes()), Ok(&b"bar"[..]));
+    assert_eq!(strtab.get(9), Err(Error::UnterminatedString { offset: 9 }));
+    assert_eq!(
+        strtab.get(12),
+        Err(Error::InvalidStringOffset {
+            offset: 12,
+            size: 12
+        })
+    );
+    let strings: Vec<_> = strtab.iter().collect();
+    assert_eq!(
+        strings,
+        vec![(0, &b""[..]), (1, &b"foo"[..]), (5, &b"bar"[..])]
+    );
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let shstrtab = elf.shstrtab().expect("no .shstrtab");
+    assert!(shstrtab.iter().any(|(_, s)| s == b".gnu_debuglink"));
+    assert_eq!(
+        elf.lookup_section(b".shstrtab")
+            .and_then(|s| s.string_table())
+            .map(|s| s.len()),
+        Some(shstrtab.len())
+    );
+    // The test binary is stripped.
+    assert!(elf.strtab().is_none());
+    let dynstr = elf.dynstr().expect("no dynamic string table");
+    assert_eq!(dynstr.len(), 0x682);
+    assert!(dynstr.iter().any(|(_, s)| s == b"libc.so.6"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_section_name_index() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let index = elf.section_name_index();
+    assert_eq!(index.len(), elf.section_header_iter().count());
+    for section in elf.section_header_iter() {
+        let name = section.section_name().unwrap();
+        assert_eq!(
+            index.lookup(name).map(|s| s.offset()),
+            elf.lookup_section(name).map(|s| s.offset())
+        );
+    }
+    assert_eq!(index.index_of(b".text"), Some(14));
+    // The NULL section and any later unnamed sections share the empty name.
+    assert_eq!(index.index_of(b""), Some(0));
+    assert!(index.lookup(b".debug_info").is_none());
+}
+
+#[test]
+fn test_hash_lookup() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    assert_eq!(gnu_hash(b""), 5381);
+    assert_eq!(gnu_hash(b"printf"), 0x156b2bb8);
+    assert_eq!(sysv_hash(b"printf"), 0x077905a6);
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    assert!(elf.sysv_hash_table().is_none());
+    let symbol = elf.lookup_dynamic_symbol(b"ls_mode").expect("no ls_mode");
+    assert_eq!(symbol.value(), 0x2201e0);
+    assert_eq!(
+        elf.lookup_dynamic_symbol(b"version_etc_copyright")
+            .map(|s| s.size()),
+        Some(47)
+    );
+    // Imported symbols are not definitions.
+    assert!(elf
+        .dynamic_symbol_iter()
+        .unwrap()
+        .any(|s| s.symbol_name() == Some(b"malloc")));
+    assert!(elf.lookup_dynamic_symbol(b"malloc").is_none());
+    assert!(elf.lookup_dynamic_symbol(b"no_such_symbol").is_none());
+
+    let mut elf_buf = std::fs::read(TEST_HASH_LIB).expect("failed to read file");
+    let check = |elf: &Elf| {
+        let gnu = GnuHashTable::find(elf).expect("no GNU hash table");
+        let sysv = elf.sysv_hash_table().expect("no SysV hash table");
+        assert_eq!(sysv.symbol_num(), 5);
+        for (name, value) in [
+            (&b"plugin_init"[..], 0x2b0),
+            (b"plugin_run", 0x2c0),
+            (b"counter", 0x2000),
+            (b"plugin_fini", 0x2d0),
+        ] {
+            assert_eq!(gnu.lookup(name).map(|s| s.value()), Some(value));
+            assert_eq!(sysv.lookup(name).map(|s| s.value()), Some(value));
+        }
+        assert!(gnu.lookup(b"plugin").is_none());
+        assert!(sysv.lookup(b"plugin").is_none());
+        assert!(!gnu.bloom_filter_matches(gnu_hash(b"plugin")));
+    };
+    check(&Elf::from_bytes(&elf_buf).expect("fail to load elf file"));
+
+    // A bloom shift of at least the word size is rejected rather than
+    // overflowing, and lookups fall back to the SysV table.
+    let mut corrupt_buf = elf_buf.clone();
+    let shift_offset = {
+        let elf = Elf::from_bytes(&corrupt_buf).expect("fail to load elf file");
+        elf.lookup_section(b".gnu.hash").unwrap().offset() as usize + 12
+    };
+    corrupt_buf[shift_offset..shift_offset + 4].copy_from_slice(&64u32.to_le_bytes());
+    let elf = Elf::from_bytes(&corrupt_buf).expect("fail to load elf file");
+    assert!(elf.gnu_hash_table().is_none());
+    assert_eq!(
+        elf.lookup_dynamic_symbol(b"plugin_run").map(|s| s.value()),
+        Some(0x2c0)
+    );
+
+    // Without section headers the tables are found through PT_DYNAMIC.
+    elf_buf[0x28..0x30].fill(0);
+    elf_buf[0x3c..0x40].fill(0);
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    assert_eq!(elf.section_header_iter().count(), 0);
+    check(&elf);
+}
+
+#[test]
+fn test_symbol_versions() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+    use std::cmp::Ordering;
+
+    let elf_buf = read_test_elf();
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let versym = elf.versym_table().expect("no .gnu.version");
+    assert_eq!(versym.iter().count(), 149);
+    assert_eq!(versym.get(5), Some(VersionIndex(4)));
+    assert!(elf.verdef_iter().is_none());
+
+    let needs: Vec<_> = elf.verneed_iter().expect("no .gnu.version_r").collect();
+    assert_eq!(needs.len(), 1);
+    assert_eq!(needs[0].file(), Some(&b"libc.so.6"[..]));
+    let names: Vec<_> = needs[0].aux_iter().map(|a| a.name().unwrap()).collect();
+    assert_eq!(
+        names,
+        vec![
+            &b"GLIBC_2.14"[..],
+            b"GLIBC_2.4",
+            b"GLIBC_2.17",
+            b"GLIBC_2.3.4",
+            b"GLIBC_2.2.5",
+            b"GLIBC_2.3",
+        ]
+    );
+    assert_eq!(needs[0].max_version(), Some(&b"GLIBC_2.17"[..]));
+    assert_eq!(
+        compare_versions(b"GLIBC_2.14", b"GLIBC_2.2.5"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        compare_versions(b"GLIBC_2.3", b"GLIBC_2.3.0"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare_versions(b"GLIBC_PRIVATE", b"GLIBC_2.0"),
+        Ordering::Less
+    );
+
+    let symbols: Vec<_> = elf.dynamic_symbol_iter().unwrap().collect();
+    let version = symbols[5].version().expect("no version");
+    assert_eq!(symbols[5].symbol_name(), Some(&b"__snprintf_chk"[..]));
+    assert_eq!(version.name, b"GLIBC_2.3.4");
+    assert_eq!(version.file, Some(&b"libc.so.6"[..]));
+    assert_eq!(version.to_string(), "@GLIBC_2.3.4");
+    assert!(symbols[0].version().is_none());
+    assert!(symbols[0].version_index().unwrap().is_local());
+
+    let elf_buf = std::fs::read(TEST_VERSIONED_LIB).expect("failed to read file");
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let defs: Vec<_> = elf
+        .verdef_iter()
+        .expect("no .gnu.version_d")
+        .map(|d| (d.index(), d.flags(), d.name_iter().collect::<Vec<_>>()))
+        .collect();
+    assert_eq!(
+        defs,
+        vec![
+            (1, VersionFlags::VER_FLG_BASE, vec![&b"libversioned.so"[..]]),
+            (2, VersionFlags::empty(), vec![&b"VERS_1"[..]]),
+            (3, VersionFlags::empty(), vec![&b"VERS_2"[..], b"VERS_1"]),
+        ]
+    );
+    let names: Vec<_> = elf
+        .dynamic_symbol_iter()
+        .unwrap()
+        .skip(1)
+        .map(|s| {
+            let version = s.version().map(|v| v.to_string()).unwrap_or_default();
+            format!(
+                "{}{}",
+                std::str::from_utf8(s.symbol_name().unwrap()).unwrap(),
+                version
+            )
+        })
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "api@@VERS_2",
+            "api@VERS_1",
+            "VERS_1@@VERS_1",
+            "old_api@@VERS_1",
+            "new_api@@VERS_2",
+            "VERS_2@@VERS_2",
+        ]
+    );
+
+    // Unversioned lookups bind to the default version.
+    let api = elf.lookup_dynamic_symbol(b"api").expect("no api");
+    assert_eq!(api.version().map(|v| v.name), Some(&b"VERS_2"[..]));
+    assert!(!api.version().unwrap().hidden);
+
+    // Without section headers the tables are found through PT_DYNAMIC.
+    let mut elf_buf = elf_buf.clone();
+    elf_buf[0x28..0x30].fill(0);
+    elf_buf[0x3c..0x40].fill(0);
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    assert_eq!(elf.verdef_iter().map(|d| d.count()), Some(3));
+    let api = elf.lookup_dynamic_symbol(b"api").expect("no api");
+    assert_eq!(
+        api.version().map(|v| v.to_string()),
+        Some("@@VERS_2".into())
+    );
+}
+
+#[test]
+fn test_compression_header() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let plain_buf = std::fs::read(TEST_DEBUG_OBJ).expect("failed to read file");
+    let plain = Elf::from_bytes(&plain_buf).expect("fail to load elf file");
+    let info_size = plain.lookup_section(b".debug_info").unwrap().size();
+    assert!(plain
+        .section_header_iter()
+        .all(|s| s.compression_header().is_none()));
+
+    for (path, name, ch_type) in [
+        (
+            TEST_DEBUG_OBJ_ZLIB,
+            &b".debug_info"[..],
+            CompressionType::ELFCOMPRESS_ZLIB,
+        ),
+        (
+            TEST_DEBUG_OBJ_ZSTD,
+            b".debug_info",
+            CompressionType::ELFCOMPRESS_ZSTD,
+        ),
+        (
+            TEST_DEBUG_OBJ_ZLIB_GNU,
+            b".zdebug_info",
+            CompressionType::ELFCOMPRESS_ZLIB,
+        ),
+    ] {
+        let elf_buf = std::fs::read(path).expect("failed to read file");
+        let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+        let header = elf
+            .lookup_section(name)
+            .and_then(|s| s.compression_header())
+            .expect("section is not compressed");
+        assert_eq!(header.ch_type, ch_type);
+        assert_eq!(header.size, info_size);
+        // Only debug sections are compressed.
+        assert!(elf
+            .lookup_section(b".shstrtab")
+            .unwrap()
+            .compression_header()
+            .is_none());
+    }
+}
+
+#[cfg(all(feature = "zlib", feature = "zstd"))]
+#[test]
+fn test_decompressed_content() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let plain_buf = std::fs::read(TEST_DEBUG_OBJ).expect("failed to read file");
+    let plain = Elf::from_bytes(&plain_buf).expect("fail to load elf file");
+
+    for path in [
+        TEST_DEBUG_OBJ_ZLIB,
+        TEST_DEBUG_OBJ_ZSTD,
+        TEST_DEBUG_OBJ_ZLIB_GNU,
+    ] {
+        let elf_buf = std::fs::read(path).expect("failed to read file");
+        let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+        for name in [&b"info"[..], b"abbrev", b"line", b"str"] {
+            let expected = plain
+                .lookup_section(&[&b".debug_"[..], name].concat())
+                .unwrap()
+                .content()
+                .unwrap();
+            let section = elf
+                .lookup_section(&[&b".debug_"[..], name].concat())
+                .or_else(|| elf.lookup_section(&[&b".zdebug_"[..], name].concat()))
+                .unwrap();
+            assert_eq!(section.decompressed_content().unwrap(), expected);
+        }
+    }
+
+    // Corrupt the compressed `.debug_info` data.
+    let mut elf_buf = std::fs::read(TEST_DEBUG_OBJ_ZLIB).expect("failed to read file");
+    let offset = {
+        let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+        elf.lookup_section(b".debug_info").unwrap().offset() as usize
+    };
+    elf_buf[offset + 24..offset + 32].fill(0xff);
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    assert_eq!(
+        elf.lookup_section(b".debug_info")
+            .unwrap()
+            .decompressed_content(),
+        Err(Error::DecompressionFailed)
+    );
+
+    // An oversized `ch_size` fails without allocating that much up front,
+    // as does one smaller than the data.
+    for path in [TEST_DEBUG_OBJ_ZLIB, TEST_DEBUG_OBJ_ZSTD] {
+        for ch_size in [1u64 << 40, 1] {
+            let mut elf_buf = std::fs::read(path).expect("failed to read file");
+            let offset = {
+                let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+                elf.lookup_section(b".debug_info").unwrap().offset() as usize
+            };
+            elf_buf[offset + 8..offset + 16].copy_from_slice(&ch_size.to_le_bytes());
+            let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+            assert_eq!(
+                elf.lookup_section(b".debug_info")
+                    .unwrap()
+                    .decompressed_content(),
+                Err(Error::DecompressionFailed)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "gimli")]
+#[test]
+fn test_dwarf() {
+    extern crate elf_rs;
+
+    use elf_rs::gimli;
+    use elf_rs::*;
+
+    /// Names of the entries tagged `tag` in `unit`.
+    fn entry_names(
+        dwarf: &gimli::Dwarf<DwarfReader>,
+        unit: &gimli::Unit<DwarfReader>,
+        tag: gimli::DwTag,
+    ) -> Vec<String> {
+        let mut names = Vec::new();
+        let mut entries = unit.entries();
+        while let Some((_, entry)) = entries.next_dfs().unwrap() {
+            if entry.tag() != tag {
+                continue;
+            }
+            let origin = match entry.attr_value(gimli::DW_AT_abstract_origin).unwrap() {
+                Some(gimli::AttributeValue::UnitRef(offset)) => Some(unit.entry(offset).unwrap()),
+                _ => None,
+            };
+            let name = origin
+                .as_ref()
+                .unwrap_or(entry)
+                .attr_value(gimli::DW_AT_name)
+                .unwrap();
+            if let Some(name) = name {
+                let name = dwarf.attr_string(unit, name).unwrap();
+                names.push(name.to_string_lossy().into_owned());
+            }
+        }
+        names
+    }
+
+    let elf_buf = std::fs::read(TEST_DEBUGINFO).expect("failed to read file");
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let data = elf.dwarf_data().unwrap();
+    assert_eq!(data.endian(), gimli::RunTimeEndian::Little);
+    let dwarf = data.dwarf();
+    let header = dwarf.units().next().unwrap().expect("no compilation unit");
+    let unit = dwarf.unit(header).unwrap();
+    assert_eq!(unit.name.unwrap().to_string_lossy(), "debuginfo.c");
+    let functions = entry_names(&dwarf, &unit, gimli::DW_TAG_subprogram);
+    assert!(functions.iter().any(|f| f == "sum_of_squares"));
+    assert!(functions.iter().any(|f| f == "main"));
+    assert_eq!(
+        entry_names(&dwarf, &unit, gimli::DW_TAG_inlined_subroutine),
+        ["square"]
+    );
+
+    // Compressed sections are decompressed, or rejected without the
+    // decompression features.
+    let zlib_buf = std::fs::read(TEST_DEBUGINFO_ZLIB).expect("failed to read file");
+    let zlib = Elf::from_bytes(&zlib_buf).expect("fail to load elf file");
+    #[cfg(feature = "zlib")]
+    {
+        use gimli::Section;
+
+        let zlib_data = zlib.dwarf_data().unwrap();
+        let zlib_dwarf = zlib_data.dwarf();
+        assert_eq!(
+            zlib_dwarf.debug_info.reader().slice(),
+            dwarf.debug_info.reader().slice()
+        );
+        assert_eq!(
+            zlib_dwarf.debug_line.reader().slice(),
+            dwarf.debug_line.reader().slice()
+        );
+    }
+    #[cfg(not(feature = "zlib"))]
+    assert_eq!(
+        zlib.dwarf_data().unwrap_err(),
+        Error::UnsupportedCompression(1)
+    );
+
+    // `.zdebug_*` sections stand in for missing `.debug_*` ones.
+    let gnu_buf = std::fs::read(TEST_DEBUG_OBJ_ZLIB_GNU).expect("failed to read file");
+    let gnu = Elf::from_bytes(&gnu_buf).expect("fail to load elf file");
+    #[cfg(feature = "zlib")]
+    {
+        use gimli::Section;
+
+        let plain_buf = std::fs::read(TEST_DEBUG_OBJ).expect("failed to read file");
+        let plain = Elf::from_bytes(&plain_buf).expect("fail to load elf file");
+        let plain_data = plain.dwarf_data().unwrap();
+        let plain_dwarf = plain_data.dwarf();
+        let gnu_data = gnu.dwarf_data().unwrap();
+        let gnu_dwarf = gnu_data.dwarf();
+        assert!(!gnu_dwarf.debug_info.reader().is_empty());
+        assert_eq!(
+            gnu_dwarf.debug_info.reader().slice(),
+            plain_dwarf.debug_info.reader().slice()
+        );
+        assert_eq!(
+            gnu_dwarf.debug_abbrev.reader().slice(),
+            plain_dwarf.debug_abbrev.reader().slice()
+        );
+        assert_eq!(
+            gnu_dwarf.debug_line.reader().slice(),
+            plain_dwarf.debug_line.reader().slice()
+        );
+    }
+    #[cfg(not(feature = "zlib"))]
+    assert_eq!(
+        gnu.dwarf_data().unwrap_err(),
+        Error::UnsupportedCompression(1)
+    );
+
+    // The skeleton unit of a split DWARF executable points to the full unit
+    // in the `.dwo` file.
+    let split_buf = std::fs::read(TEST_SPLIT).expect("failed to read file");
+    let split = Elf::from_bytes(&split_buf).expect("fail to load elf file");
+    let split_data = split.dwarf_data().unwrap();
+    let split_dwarf = split_data.dwarf();
+    let header = split_dwarf.units().next().unwrap().unwrap();
+    let skeleton = split_dwarf.unit(header).unwrap();
+    let dwo_id = skeleton.dwo_id.expect("not a skeleton unit");
+    assert!(entry_names(&split_dwarf, &skeleton, gimli::DW_TAG_subprogram).is_empty());
+
+    let dwo_buf = std::fs::read(TEST_SPLIT_DWO).expect("failed to read file");
+    let dwo = Elf::from_bytes(&dwo_buf).expect("fail to load elf file");
+    assert!(dwo.lookup_section(b".debug_info").is_none());
+    let dwo_data = DwarfData::load_dwo(&dwo).unwrap();
+    let dwo_dwarf = dwo_data.dwo_dwarf(&split_dwarf);
+    let header = dwo_dwarf.units().next().unwrap().unwrap();
+    let mut unit = dwo_dwarf.unit(header).unwrap();
+    assert_eq!(unit.dwo_id, Some(dwo_id));
+    unit.copy_relocated_attributes(&skeleton);
+    assert_eq!(unit.low_pc, skeleton.low_pc);
+    let functions = entry_names(&dwo_dwarf, &unit, gimli::DW_TAG_subprogram);
+    assert!(functions.iter().any(|f| f == "sum_of_squares"));
+
+    // The `.dwo` sections are not loaded as regular DWARF.
+    let data = DwarfData::load(&dwo).unwrap();
+    assert!(data.dwarf().units().next().unwrap().is_none());
+}
+
+#[cfg(feature = "symbolize")]
+#[test]
+fn test_symbolizer() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    fn summary(frames: &Frames) -> Vec<(Option<&str>, Option<&str>, Option<u32>)> {
+        frames
+            .iter()
+            .map(|f| {
+                let file = f.file.as_deref().map(|f| f.rsplit('/').next().unwrap());
+                (f.function.as_deref(), file, f.line)
+            })
+            .collect()
+    }
+
+    let elf_buf = std::fs::read(TEST_DEBUGINFO).expect("failed to read file");
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let symbolizer = Symbolizer::new(&elf).unwrap();
+
+    // `square` is inlined into the loop of `sum_of_squares`.
+    let frames = symbolizer.symbolize(0x1152);
+    assert_eq!(
+        summary(&frames),
+        [
+            (Some("square"), Some("debuginfo.c"), Some(3)),
+            (Some("sum_of_squares"), Some("debuginfo.c"), Some(10)),
+        ]
+    );
+    assert_eq!(
+        frames.outermost().unwrap().function.as_deref(),
+        Some("sum_of_squares")
+    );
+    assert_eq!(
+        summary(&symbolizer.symbolize(0x1040)),
+        [(Some("main"), Some("debuginfo.c"), Some(17))]
+    );
+    // `_start` has no debug info, so only its symbol is known.
+    assert_eq!(
+        summary(&symbolizer.symbolize(0x1055)),
+        [(Some("_start"), None, None)]
+    );
+    assert!(symbolizer.symbolize(0x10).is_empty());
+
+    // Runtime addresses of the PIE loaded at a random base.
+    let base = 0x5555_5555_4000;
+    let symbolizer = symbolizer.with_load_bias(base);
+    assert_eq!(symbolizer.load_bias(), base);
+    assert_eq!(
+        summary(&symbolizer.symbolize(base + 0x1152)),
+        summary(&frames)
+    );
+
+    // Compressed debug sections give the same frames.
+    #[cfg(feature = "zlib")]
+    {
+        let zlib_buf = std::fs::read(TEST_DEBUGINFO_ZLIB).expect("failed to read file");
+        let zlib = Elf::from_bytes(&zlib_buf).expect("fail to load elf file");
+        let symbolizer = Symbolizer::new(&zlib).unwrap();
+        assert_eq!(summary(&symbolizer.symbolize(0x1152)), summary(&frames));
+    }
+
+    // Without a `.symtab`, the `.dynsym` symbols are used.
+    let elf_buf = std::fs::read(TEST_HASH_LIB).expect("failed to read file");
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let symbolizer = Symbolizer::new(&elf).unwrap();
+    assert_eq!(
+        summary(&symbolizer.symbolize(0x2c4)),
+        [(Some("plugin_run"), None, None)]
+    );
+    assert_eq!(
+        summary(&symbolizer.symbolize(0x2002)),
+        [(Some("counter"), None, None)]
+    );
+    // Past the end of `plugin_fini`.
+    assert!(symbolizer.symbolize(0x2de).is_empty());
+
+    // A larger symbol covers addresses past the end of the ones inside it.
+    let mut elf_buf = elf_buf.clone();
+    let dynsym = elf.lookup_section(b".dynsym").unwrap().offset() as usize;
+    let st_size = dynsym + 24 + 16;
+    elf_buf[st_size..st_size + 8].copy_from_slice(&0x40u64.to_le_bytes());
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let symbolizer = Symbolizer::new(&elf).unwrap();
+    assert_eq!(
+        summary(&symbolizer.symbolize(0x2c4)),
+        [(Some("plugin_run"), None, None)]
+    );
+    for address in [0x2cc, 0x2de] {
+        assert_eq!(
+            summary(&symbolizer.symbolize(address)),
+            [(Some("plugin_init"), None, None)]
+        );
+    }
+    assert!(symbolizer.symbolize(0x2f0).is_empty());
+}
+
+#[test]
+fn test_debug_link() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+
+    let elf_buf = std::fs::read(TEST_ELF_FILE).expect("failed to read file");
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    assert_eq!(
+        elf.debug_link(),
+        Some(DebugLink {
+            file_name: b"67f9a28e66f4d7ec4baf31cfbf68d0410f0ae6.debug",
+            crc: 0x72896c50,
+        })
+    );
+    assert_eq!(elf.debug_alt_link(), None);
+
+    let elf_buf = std::fs::read(TEST_DEBUGLINK).expect("failed to read file");
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    assert_eq!(
+        elf.debug_link(),
+        Some(DebugLink {
+            file_name: b"debuglink.debug",
+            crc: 0x6cba71de,
+        })
+    );
+
+    let debug_buf = std::fs::read(TEST_DEBUGLINK_DEBUG).expect("failed to read file");
+    let debug = Elf::from_bytes(&debug_buf).expect("fail to load elf file");
+    assert_eq!(debug.debug_link(), None);
+    assert_eq!(debug.build_id(), elf.build_id());
+    let alt_link = debug.debug_alt_link().unwrap();
+    assert_eq!(alt_link.file_name, b"sup.debug");
+    assert_eq!(
+        BuildId::new(alt_link.build_id).to_string(),
+        "00935eae7e8d55d9b784950eef0da4d123cc9f1f"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_debug_file_resolver() {
+    extern crate elf_rs;
+
+    use elf_rs::*;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    fn copy(from: &str, to: &Path) {
+        fs::create_dir_all(to.parent().unwrap()).unwrap();
+        fs::copy(from, to).unwrap();
+    }
+
+    /// Removes the directory even if an assertion fails.
+    struct TempDir(PathBuf);
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
         }
     }
+
+    let tmp =
+        TempDir(std::env::temp_dir().join(format!("elf_rs-debug-file-{}", std::process::id())));
+    let bin = tmp.0.join("bin");
+    let root = tmp.0.join("root");
+    let exe_path = bin.join("debuglink");
+    copy(TEST_DEBUGLINK, &exe_path);
+
+    let elf_buf = fs::read(&exe_path).expect("failed to read file");
+    let elf = Elf::from_bytes(&elf_buf).expect("fail to load elf file");
+    let resolver = DebugFileResolver::new().with_debug_dirs([&root]);
+    assert_eq!(resolver.debug_dirs(), std::slice::from_ref(&root));
+    assert_eq!(resolver.find_debug_file(&elf, &exe_path), None);
+
+    // A debug file with the wrong CRC is skipped.
+    let debug_data = fs::read(TEST_DEBUGLINK_DEBUG).unwrap();
+    let mut corrupt = debug_data.clone();
+    *corrupt.last_mut().unwrap() ^= 1;
+    fs::write(bin.join("debuglink.debug"), &corrupt).unwrap();
+    let dot_debug = bin.join(".debug/debuglink.debug");
+    copy(TEST_DEBUGLINK_DEBUG, &dot_debug);
+    let found = resolver.find_debug_file(&elf, &exe_path).unwrap();
+    assert_eq!(found.path, dot_debug);
+    assert_eq!(found.data, debug_data);
+
+    // Build-id paths take precedence.
+    let build_id_path = root.join(".build-id/00/935eae7e8d55d9b784950eef0da4d123cc9f1f.debug");
+    copy(TEST_DEBUGLINK_DEBUG, &build_id_path);
+    let found = resolver.find_debug_file(&elf, &exe_path).unwrap();
+    assert_eq!(found.path, build_id_path);
+
+    // The debug directory mirrors the directory of the file.
+    fs::remove_file(&build_id_path).unwrap();
+    fs::remove_file(&dot_debug).unwrap();
+    let canonical_bin = bin.canonicalize().unwrap();
+    let mirrored = root
+        .join(canonical_bin.strip_prefix("/").unwrap())
+        .join("debuglink.debug");
+    copy(TEST_DEBUGLINK_DEBUG, &mirrored);
+    let found = resolver.find_debug_file(&elf, &exe_path).unwrap();
+    assert_eq!(found.path, mirrored);
+
+    // The supplementary file must have the recorded build-id.
+    let debug = Elf::from_bytes(&debug_data).expect("fail to load elf file");
+    assert_eq!(resolver.find_alt_file(&debug, &mirrored), None);
+    let sup = mirrored.with_file_name("sup.debug");
+    copy(TEST_HASH_LIB, &sup);
+    assert_eq!(resolver.find_alt_file(&debug, &mirrored), None);
+    copy(TEST_DEBUGINFO, &sup);
+    let found = resolver.find_alt_file(&debug, &mirrored).unwrap();
+    assert_eq!(found.path, sup);
 }